@@ -0,0 +1,128 @@
+// crates/limit-benchmark/src/comparison.rs
+use crate::harness::HarnessReport;
+use serde::{Serialize, Deserialize};
+
+/// Side-by-side comparison of named harness runs, e.g. one run per
+/// graph-query strategy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BenchmarkComparison {
+    pub runs: Vec<(String, HarnessReport)>,
+}
+
+/// One row of the comparison table: a metric's value for every run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricComparison {
+    pub metric: String,
+    pub higher_is_better: bool,
+    /// Values in run order
+    pub values: Vec<f64>,
+    pub best_run: Option<String>,
+}
+
+/// Compared metrics and whether larger values win
+const METRICS: [(&str, bool); 5] = [
+    ("avg_latency_ms", false),
+    ("total_time_ms", false),
+    ("throughput_qps", true),
+    ("avg_intent_coverage", true),
+    ("success_rate", true),
+];
+
+impl BenchmarkComparison {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_run(&mut self, name: &str, report: HarnessReport) {
+        self.runs.push((name.to_string(), report));
+    }
+
+    /// Per-metric table with the best run for each metric (first run wins ties)
+    pub fn table(&self) -> Vec<MetricComparison> {
+        METRICS
+            .iter()
+            .map(|&(metric, higher_is_better)| {
+                let values: Vec<f64> = self
+                    .runs
+                    .iter()
+                    .map(|(_, report)| metric_value(report, metric))
+                    .collect();
+
+                let mut best: Option<usize> = None;
+                for (idx, &value) in values.iter().enumerate() {
+                    let better = match best {
+                        None => !value.is_nan(),
+                        Some(b) if higher_is_better => value > values[b],
+                        Some(b) => value < values[b],
+                    };
+                    if better {
+                        best = Some(idx);
+                    }
+                }
+
+                MetricComparison {
+                    metric: metric.to_string(),
+                    higher_is_better,
+                    values,
+                    best_run: best.map(|idx| self.runs[idx].0.clone()),
+                }
+            })
+            .collect()
+    }
+
+    /// Name of the best run for `metric`, if the metric is known and any run exists
+    pub fn best_run(&self, metric: &str) -> Option<String> {
+        self.table()
+            .into_iter()
+            .find(|row| row.metric == metric)
+            .and_then(|row| row.best_run)
+    }
+
+    /// Serialize the comparison table as JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        let runs: Vec<&str> = self.runs.iter().map(|(name, _)| name.as_str()).collect();
+        serde_json::to_string(&serde_json::json!({
+            "runs": runs,
+            "metrics": self.table(),
+        }))
+    }
+
+    /// Render the comparison table as CSV: one row per metric, one column per
+    /// run, plus a trailing `best` column
+    pub fn to_csv(&self) -> String {
+        let mut header = vec!["metric".to_string()];
+        header.extend(self.runs.iter().map(|(name, _)| csv_field(name)));
+        header.push("best".to_string());
+
+        let mut lines = vec![header.join(",")];
+        for row in self.table() {
+            let mut fields = vec![row.metric];
+            fields.extend(row.values.iter().map(|v| v.to_string()));
+            fields.push(row.best_run.as_deref().map(csv_field).unwrap_or_default());
+            lines.push(fields.join(","));
+        }
+        lines.join("\n") + "\n"
+    }
+}
+
+fn metric_value(report: &HarnessReport, metric: &str) -> f64 {
+    match metric {
+        "avg_latency_ms" => report.avg_latency_ms,
+        "total_time_ms" => report.total_time_ms,
+        "throughput_qps" => report.throughput_qps,
+        "avg_intent_coverage" => report.avg_intent_coverage as f64,
+        "success_rate" if report.total_queries > 0 => {
+            report.successful_queries as f64 / report.total_queries as f64
+        }
+        _ => 0.0,
+    }
+}
+
+/// Quote run names containing separators or quotes
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
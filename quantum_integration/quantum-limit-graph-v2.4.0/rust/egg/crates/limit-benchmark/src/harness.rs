@@ -1,7 +1,10 @@
 // crates/limit-benchmark/src/harness.rs
 use crate::metrics::{GraphMetrics, QueryMetrics};
 use crate::multi::{MultiIntentQuery, MultiIntentResult};
+use anyhow::{Context, Result};
 use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::Path;
 use std::time::Instant;
 
 /// Benchmark harness for SARS-CoV-2 knowledge graph
@@ -9,6 +12,8 @@ pub struct BenchmarkHarness {
     pub name: String,
     pub queries: Vec<MultiIntentQuery>,
     pub results: Vec<BenchmarkResult>,
+    pub priority_order: bool,
+    pub query_timeout_ms: Option<f64>,
 }
 
 impl BenchmarkHarness {
@@ -17,13 +22,36 @@ impl BenchmarkHarness {
             name,
             queries: vec![],
             results: vec![],
+            priority_order: false,
+            query_timeout_ms: None,
         }
     }
 
+    /// Execute each query's intents in descending priority order
+    pub fn with_priority_order(mut self) -> Self {
+        self.priority_order = true;
+        self
+    }
+
+    /// Per-query time budget used to decide which intents were reached
+    pub fn with_query_timeout(mut self, timeout_ms: f64) -> Self {
+        self.query_timeout_ms = Some(timeout_ms);
+        self
+    }
+
     pub fn add_query(&mut self, query: MultiIntentQuery) {
         self.queries.push(query);
     }
 
+    /// Append queries from a JSON array of `MultiIntentQuery` (a versioned query suite)
+    pub fn load_queries(&mut self, path: &Path) -> Result<()> {
+        let content = fs::read_to_string(path).with_context(|| path.display().to_string())?;
+        let queries: Vec<MultiIntentQuery> =
+            serde_json::from_str(&content).with_context(|| path.display().to_string())?;
+        self.queries.extend(queries);
+        Ok(())
+    }
+
     pub fn run<F>(&mut self, executor: F) -> HarnessReport
     where
         F: Fn(&MultiIntentQuery) -> MultiIntentResult,
@@ -32,8 +60,14 @@ impl BenchmarkHarness {
         let start = Instant::now();
 
         for query in &self.queries {
+            let ordered = if self.priority_order {
+                query.sorted_by_priority()
+            } else {
+                query.clone()
+            };
+
             let query_start = Instant::now();
-            let result = executor(query);
+            let result = executor(&ordered);
             let latency = query_start.elapsed().as_millis() as f64;
 
             self.results.push(BenchmarkResult {
@@ -41,6 +75,7 @@ impl BenchmarkHarness {
                 latency_ms: latency,
                 success: result.success,
                 intent_coverage: result.intent_coverage,
+                intents_reached: self.intents_reached(ordered.intents.len(), &result.intent_latencies_ms),
             });
         }
 
@@ -48,6 +83,26 @@ impl BenchmarkHarness {
         self.generate_report(total_time)
     }
 
+    /// Intents are resolved in execution order, so intent `i` is reached if the
+    /// measured latencies of intents `0..=i` fit the per-query timeout. Without
+    /// a timeout every intent is reached; when the executor didn't measure a
+    /// latency for every intent, reach is unknown (`None`).
+    fn intents_reached(&self, intent_count: usize, intent_latencies_ms: &[f64]) -> Vec<Option<bool>> {
+        let Some(timeout) = self.query_timeout_ms else {
+            return vec![Some(true); intent_count];
+        };
+        if intent_latencies_ms.len() != intent_count {
+            return vec![None; intent_count];
+        }
+        intent_latencies_ms
+            .iter()
+            .scan(0.0, |elapsed, latency| {
+                *elapsed += latency;
+                Some(Some(*elapsed <= timeout))
+            })
+            .collect()
+    }
+
     fn generate_report(&self, total_time_ms: f64) -> HarnessReport {
         let total = self.results.len();
         let successful = self.results.iter().filter(|r| r.success).count();
@@ -72,6 +127,9 @@ pub struct BenchmarkResult {
     pub latency_ms: f64,
     pub success: bool,
     pub intent_coverage: f32,
+    /// Whether each intent, in execution order, finished within the query
+    /// timeout; `None` when per-intent latencies weren't measured
+    pub intents_reached: Vec<Option<bool>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -3,8 +3,10 @@ pub mod multi;
 pub mod metrics;
 pub mod harness;
 pub mod provenance;
+pub mod comparison;
 
-pub use multi::{MultiIntentQuery, Intent, IntentType, MultiIntentHarness, MultiIntentResult, BenchmarkSummary};
+pub use multi::{classify_intent, MultiIntentQuery, Intent, IntentType, MultiIntentHarness, MultiIntentResult, BenchmarkSummary, BackendSummary, Relation};
 pub use metrics::{GraphMetrics, QueryMetrics};
 pub use harness::{BenchmarkHarness, BenchmarkResult, HarnessReport};
 pub use provenance::{ProvenanceRecord, ProvenanceTracker};
+pub use comparison::{BenchmarkComparison, MetricComparison};
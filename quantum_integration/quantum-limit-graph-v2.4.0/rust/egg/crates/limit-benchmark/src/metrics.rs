@@ -10,17 +10,41 @@ pub struct GraphMetrics {
     pub density: f32,
     pub coverage_score: f32,
     pub provenance_score: f32,
+    /// Whether degree and density were computed for a directed graph
+    #[serde(default)]
+    pub directed: bool,
 }
 
 impl GraphMetrics {
+    /// Metrics treating every edge as undirected
     pub fn compute(node_count: usize, edge_count: usize) -> Self {
+        Self::compute_with_direction(node_count, edge_count, false)
+    }
+
+    /// Metrics for a directed graph (e.g. causal relations): average degree
+    /// counts each edge once (mean out-degree) and density is relative to
+    /// n(n-1) possible ordered pairs rather than n(n-1)/2
+    pub fn compute_directed(node_count: usize, edge_count: usize) -> Self {
+        Self::compute_with_direction(node_count, edge_count, true)
+    }
+
+    pub fn compute_with_direction(node_count: usize, edge_count: usize, directed: bool) -> Self {
+        let endpoints_per_edge = if directed { 1 } else { 2 };
         let avg_degree = if node_count > 0 {
-            (2 * edge_count) as f32 / node_count as f32
+            (endpoints_per_edge * edge_count) as f32 / node_count as f32
         } else {
             0.0
         };
 
-        let max_edges = node_count * (node_count - 1) / 2;
+        // Fewer than two nodes admit no edges (and `node_count - 1` would
+        // underflow for an empty graph)
+        let max_edges = if node_count < 2 {
+            0
+        } else if directed {
+            node_count * (node_count - 1)
+        } else {
+            node_count * (node_count - 1) / 2
+        };
         let density = if max_edges > 0 {
             edge_count as f32 / max_edges as f32
         } else {
@@ -34,20 +58,31 @@ impl GraphMetrics {
             density,
             coverage_score: 0.0,
             provenance_score: 0.0,
+            directed,
         }
     }
 
+    /// Non-finite scores are recorded as 0.0
     pub fn with_coverage(mut self, coverage: f32) -> Self {
-        self.coverage_score = coverage;
+        self.coverage_score = finite_or_zero(coverage);
         self
     }
 
+    /// Non-finite scores are recorded as 0.0
     pub fn with_provenance(mut self, provenance: f32) -> Self {
-        self.provenance_score = provenance;
+        self.provenance_score = finite_or_zero(provenance);
         self
     }
 }
 
+fn finite_or_zero(value: f32) -> f32 {
+    if value.is_finite() {
+        value
+    } else {
+        0.0
+    }
+}
+
 /// Query performance metrics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryMetrics {
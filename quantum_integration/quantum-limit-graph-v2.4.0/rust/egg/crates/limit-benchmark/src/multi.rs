@@ -1,6 +1,9 @@
 // crates/limit-benchmark/src/multi.rs
 use serde::{Serialize, Deserialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// Graph edge relation label, as stored in `Edge.relation`
+pub use limit_bio_sars::Relation;
 
 /// Multi-intent query representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,6 +13,81 @@ pub struct MultiIntentQuery {
     pub context: HashMap<String, String>,
 }
 
+impl MultiIntentQuery {
+    /// Clone of this query with intents ordered by descending priority
+    pub fn sorted_by_priority(&self) -> MultiIntentQuery {
+        let mut sorted = self.clone();
+        sorted.intents.sort_by(|a, b| {
+            b.priority
+                .partial_cmp(&a.priority)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        sorted
+    }
+
+    /// Graph relations able to answer at least one of this query's intents
+    pub fn expected_relations(&self) -> HashSet<Relation> {
+        self.intents
+            .iter()
+            .flat_map(|i| i.intent_type.answering_relations())
+            .map(|r| r.to_string())
+            .collect()
+    }
+
+    /// Fraction of `expected_relations` present among a graph's relations
+    /// (1.0 when the intents expect no particular relation)
+    pub fn expected_relation_coverage(&self, graph_relations: &HashSet<Relation>) -> f32 {
+        let expected = self.expected_relations();
+        if expected.is_empty() {
+            return 1.0;
+        }
+        expected.intersection(graph_relations).count() as f32 / expected.len() as f32
+    }
+
+    /// Intent priorities scaled to sum to 1, in intent order
+    ///
+    /// Negative and NaN priorities count as 0; when none is positive every
+    /// intent gets the same weight.
+    pub fn normalized_priorities(&self) -> Vec<f32> {
+        let weights: Vec<f32> = self
+            .intents
+            .iter()
+            .map(|i| if i.priority > 0.0 { i.priority } else { 0.0 })
+            .collect();
+        let total: f32 = weights.iter().sum();
+        if total > 0.0 {
+            weights.iter().map(|w| w / total).collect()
+        } else {
+            vec![1.0 / self.intents.len() as f32; self.intents.len()]
+        }
+    }
+
+    /// Per-intent relation coverage (as in `expected_relation_coverage`, but
+    /// for each intent's own answering relations) weighted by
+    /// `normalized_priorities`, so high-priority intents count for more
+    ///
+    /// 1.0 for a query without intents.
+    pub fn weighted_relation_coverage(&self, graph_relations: &HashSet<Relation>) -> f32 {
+        if self.intents.is_empty() {
+            return 1.0;
+        }
+        self.intents
+            .iter()
+            .zip(self.normalized_priorities())
+            .map(|(intent, weight)| {
+                let answering = intent.intent_type.answering_relations();
+                let coverage = if answering.is_empty() {
+                    1.0
+                } else {
+                    answering.iter().filter(|r| graph_relations.contains(**r)).count() as f32
+                        / answering.len() as f32
+                };
+                weight * coverage
+            })
+            .sum()
+    }
+}
+
 /// Individual intent within a multi-intent query
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Intent {
@@ -19,7 +97,7 @@ pub struct Intent {
     pub domain: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum IntentType {
     Factual,
     Causal,
@@ -28,6 +106,46 @@ pub enum IntentType {
     Exploratory,
 }
 
+impl IntentType {
+    /// Relation types whose edges can answer this kind of intent
+    pub fn answering_relations(&self) -> &'static [&'static str] {
+        match self {
+            IntentType::Factual => &["encodes", "has_mutation"],
+            IntentType::Causal => &["binds_to", "inhibits", "causes"],
+            IntentType::Comparative => &["variant_of", "differs_from"],
+            IntentType::Predictive => &["evolves_to", "escapes"],
+            IntentType::Exploratory => &[],
+        }
+    }
+}
+
+/// Keyword heuristic for the intent of a raw query string, as a starting
+/// point when building `MultiIntentQuery`s by hand
+///
+/// Comparative cues ("compare", "versus") win over predictive ones ("will",
+/// "predict"), which win over causal ones ("how", "why"); anything else is
+/// `Factual`.
+pub fn classify_intent(query: &str) -> IntentType {
+    let words: HashSet<String> = query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect();
+    let mentions = |cues: &[&str]| cues.iter().any(|cue| words.contains(*cue));
+
+    if mentions(&[
+        "compare", "compared", "comparing", "comparison", "versus", "vs", "differ", "difference",
+    ]) {
+        IntentType::Comparative
+    } else if mentions(&["will", "predict", "predicted", "forecast", "future"]) {
+        IntentType::Predictive
+    } else if mentions(&["how", "why", "cause", "causes", "mechanism"]) {
+        IntentType::Causal
+    } else {
+        IntentType::Factual
+    }
+}
+
 /// Multi-intent harness for benchmarking
 pub struct MultiIntentHarness {
     pub queries: Vec<MultiIntentQuery>,
@@ -69,7 +187,47 @@ impl MultiIntentHarness {
             successful_queries: successful,
             avg_latency_ms: avg_latency,
             avg_intent_coverage: avg_coverage,
+            avg_latency_by_type: self.latency_by_type(),
+            by_backend: self.backend_summaries(),
+        }
+    }
+
+    /// Latency and coverage aggregated per backend, over results naming one
+    fn backend_summaries(&self) -> HashMap<String, BackendSummary> {
+        let mut grouped: HashMap<String, Vec<&MultiIntentResult>> = HashMap::new();
+        for result in self.results.iter().filter(|r| !r.backend.is_empty()) {
+            grouped.entry(result.backend.clone()).or_default().push(result);
         }
+        grouped
+            .into_iter()
+            .map(|(backend, results)| {
+                let count = results.len();
+                let summary = BackendSummary {
+                    queries: count,
+                    successful_queries: results.iter().filter(|r| r.success).count(),
+                    avg_latency_ms: results.iter().map(|r| r.latency_ms).sum::<f64>() / count as f64,
+                    avg_intent_coverage: results.iter().map(|r| r.intent_coverage).sum::<f32>() / count as f32,
+                };
+                (backend, summary)
+            })
+            .collect()
+    }
+
+    /// Mean per-intent latency for each intent type, over every result that
+    /// reported per-intent latencies
+    fn latency_by_type(&self) -> HashMap<IntentType, f64> {
+        let mut totals: HashMap<IntentType, (f64, usize)> = HashMap::new();
+        for (query, result) in self.queries.iter().zip(&self.results) {
+            for (intent, latency) in query.intents.iter().zip(&result.intent_latencies_ms) {
+                let total = totals.entry(intent.intent_type.clone()).or_insert((0.0, 0));
+                total.0 += latency;
+                total.1 += 1;
+            }
+        }
+        totals
+            .into_iter()
+            .map(|(intent_type, (sum, count))| (intent_type, sum / count as f64))
+            .collect()
     }
 }
 
@@ -80,6 +238,14 @@ pub struct MultiIntentResult {
     pub latency_ms: f64,
     pub intent_coverage: f32,
     pub provenance: Vec<String>,
+    /// Latency of each intent, in the order the executor received them;
+    /// leave empty when not measured
+    #[serde(default)]
+    pub intent_latencies_ms: Vec<f64>,
+    /// Backend that answered the query (e.g. `simulator`, `qpu`, matching
+    /// `RDPoint.backend`); leave empty when not applicable
+    #[serde(default)]
+    pub backend: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -88,6 +254,21 @@ pub struct BenchmarkSummary {
     pub successful_queries: usize,
     pub avg_latency_ms: f64,
     pub avg_intent_coverage: f32,
+    /// Mean latency per intent type, from results reporting per-intent latencies
+    #[serde(default)]
+    pub avg_latency_by_type: HashMap<IntentType, f64>,
+    /// Aggregates per backend, from results naming a backend
+    #[serde(default)]
+    pub by_backend: HashMap<String, BackendSummary>,
+}
+
+/// Benchmark aggregates for the results of one backend
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackendSummary {
+    pub queries: usize,
+    pub successful_queries: usize,
+    pub avg_latency_ms: f64,
+    pub avg_intent_coverage: f32,
 }
 
 impl Default for MultiIntentHarness {
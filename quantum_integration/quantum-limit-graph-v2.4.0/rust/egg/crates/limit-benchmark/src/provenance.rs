@@ -28,6 +28,7 @@ impl ProvenanceRecord {
 }
 
 /// Provenance tracker for graph operations
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProvenanceTracker {
     records: Vec<ProvenanceRecord>,
 }
@@ -52,6 +53,16 @@ impl ProvenanceTracker {
         &self.records
     }
 
+    /// Serialize all records as compact JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Serialize all records as indented JSON for reviewers
+    pub fn to_json_pretty(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
     pub fn filter_by_source(&self, source: &str) -> Vec<&ProvenanceRecord> {
         self.records.iter().filter(|r| r.source == source).collect()
     }
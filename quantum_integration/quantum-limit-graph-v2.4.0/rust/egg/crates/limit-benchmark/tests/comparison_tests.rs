@@ -0,0 +1,50 @@
+// tests/comparison_tests.rs
+#[cfg(test)]
+mod tests {
+    use limit_benchmark::{BenchmarkComparison, HarnessReport};
+    use serde_json::Value;
+
+    fn report(name: &str, avg_latency_ms: f64, avg_intent_coverage: f32) -> HarnessReport {
+        HarnessReport {
+            benchmark_name: name.to_string(),
+            total_queries: 10,
+            successful_queries: 9,
+            total_time_ms: avg_latency_ms * 10.0,
+            avg_latency_ms,
+            avg_intent_coverage,
+            throughput_qps: 1000.0 / avg_latency_ms,
+        }
+    }
+
+    fn create_test_comparison() -> BenchmarkComparison {
+        let mut comparison = BenchmarkComparison::new();
+        comparison.add_run("bfs", report("bfs", 12.0, 0.7));
+        comparison.add_run("quantum_walk", report("quantum_walk", 8.5, 0.8));
+        comparison.add_run("vector", report("vector", 10.0, 0.95));
+        comparison
+    }
+
+    #[test]
+    fn test_best_run_per_metric() {
+        let comparison = create_test_comparison();
+
+        assert_eq!(comparison.best_run("avg_latency_ms").as_deref(), Some("quantum_walk"));
+        assert_eq!(comparison.best_run("avg_intent_coverage").as_deref(), Some("vector"));
+        assert_eq!(comparison.best_run("unknown"), None);
+    }
+
+    #[test]
+    fn test_json_and_csv_output() {
+        let comparison = create_test_comparison();
+
+        let json: Value = serde_json::from_str(&comparison.to_json().unwrap()).unwrap();
+        assert_eq!(json["runs"], serde_json::json!(["bfs", "quantum_walk", "vector"]));
+        assert_eq!(json["metrics"][0]["metric"], "avg_latency_ms");
+        assert_eq!(json["metrics"][0]["best_run"], "quantum_walk");
+
+        let csv = comparison.to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("metric,bfs,quantum_walk,vector,best"));
+        assert_eq!(lines.next(), Some("avg_latency_ms,12,8.5,10,quantum_walk"));
+    }
+}
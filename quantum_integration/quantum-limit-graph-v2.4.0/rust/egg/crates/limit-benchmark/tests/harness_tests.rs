@@ -0,0 +1,123 @@
+// tests/harness_tests.rs
+#[cfg(test)]
+mod tests {
+    use limit_benchmark::{BenchmarkHarness, Intent, IntentType, MultiIntentQuery, MultiIntentResult};
+    use std::collections::HashMap;
+    use std::fs;
+    use std::sync::Mutex;
+
+    fn intent(query: &str, priority: f32) -> Intent {
+        Intent {
+            intent_type: IntentType::Factual,
+            query: query.to_string(),
+            priority,
+            domain: None,
+        }
+    }
+
+    fn create_test_query(id: &str, intents: Vec<Intent>) -> MultiIntentQuery {
+        MultiIntentQuery {
+            id: id.to_string(),
+            intents,
+            context: HashMap::new(),
+        }
+    }
+
+    fn create_test_result(query: &MultiIntentQuery) -> MultiIntentResult {
+        MultiIntentResult {
+            query_id: query.id.clone(),
+            success: true,
+            latency_ms: 10.0,
+            intent_coverage: 1.0,
+            provenance: vec![],
+            intent_latencies_ms: vec![],
+            backend: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_priority_order_execution() {
+        let mut harness = BenchmarkHarness::new("priority".to_string()).with_priority_order();
+        harness.add_query(create_test_query(
+            "q1",
+            vec![intent("low", 0.2), intent("high", 0.9), intent("mid", 0.5)],
+        ));
+
+        let seen = Mutex::new(Vec::new());
+        harness.run(|query| {
+            seen.lock()
+                .unwrap()
+                .extend(query.intents.iter().map(|i| i.priority));
+            create_test_result(query)
+        });
+
+        assert_eq!(*seen.lock().unwrap(), vec![0.9, 0.5, 0.2]);
+    }
+
+    #[test]
+    fn test_vector_order_by_default() {
+        let mut harness = BenchmarkHarness::new("default".to_string());
+        harness.add_query(create_test_query(
+            "q1",
+            vec![intent("low", 0.2), intent("high", 0.9)],
+        ));
+
+        let seen = Mutex::new(Vec::new());
+        harness.run(|query| {
+            seen.lock()
+                .unwrap()
+                .extend(query.intents.iter().map(|i| i.priority));
+            create_test_result(query)
+        });
+
+        assert_eq!(*seen.lock().unwrap(), vec![0.2, 0.9]);
+    }
+
+    #[test]
+    fn test_timeout_cuts_off_lower_priority_intents() {
+        let mut harness = BenchmarkHarness::new("timeout".to_string())
+            .with_priority_order()
+            .with_query_timeout(100.0);
+        harness.add_query(create_test_query(
+            "q1",
+            vec![intent("low", 0.2), intent("high", 0.9), intent("mid", 0.5)],
+        ));
+        harness.add_query(create_test_query("unmeasured", vec![intent("a", 0.4), intent("b", 0.8)]));
+
+        harness.run(|query| {
+            let mut result = create_test_result(query);
+            if query.id == "q1" {
+                // Executed high, mid, low: cumulative 60, 100, 140 ms
+                result.intent_latencies_ms = vec![60.0, 40.0, 40.0];
+            }
+            result
+        });
+
+        assert_eq!(harness.results[0].intents_reached, vec![Some(true), Some(true), Some(false)]);
+        assert_eq!(harness.results[1].intents_reached, vec![None, None]);
+    }
+
+    #[test]
+    fn test_load_queries_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("queries.json");
+        let queries = vec![
+            create_test_query("spike-binding", vec![intent("How does spike bind ACE2?", 0.9)]),
+            create_test_query("omicron-escape", vec![intent("Does Omicron escape vaccines?", 0.7)]),
+        ];
+        fs::write(&path, serde_json::to_string(&queries).unwrap()).unwrap();
+
+        let mut harness = BenchmarkHarness::new("suite".to_string());
+        harness.load_queries(&path).unwrap();
+
+        let ids: Vec<&str> = harness.queries.iter().map(|q| q.id.as_str()).collect();
+        assert_eq!(ids, vec!["spike-binding", "omicron-escape"]);
+
+        // Parse errors keep the file path and the JSON error
+        fs::write(&path, "[{\"id\": 7}]").unwrap();
+        let err = harness.load_queries(&path).unwrap_err();
+        assert!(err.to_string().contains("queries.json"));
+        assert!(err.root_cause().is::<serde_json::Error>());
+        assert_eq!(harness.queries.len(), 2);
+    }
+}
@@ -0,0 +1,31 @@
+// tests/metrics_tests.rs
+#[cfg(test)]
+mod tests {
+    use limit_benchmark::GraphMetrics;
+
+    #[test]
+    fn test_directed_vs_undirected_density() {
+        let undirected = GraphMetrics::compute(4, 6);
+        let directed = GraphMetrics::compute_directed(4, 6);
+
+        // 6 edges complete an undirected K4 but fill half of its 12 ordered pairs
+        assert_eq!(undirected.density, 1.0);
+        assert_eq!(directed.density, 0.5);
+        assert_eq!(undirected.avg_degree, 3.0);
+        assert_eq!(directed.avg_degree, 1.5);
+        assert!(directed.directed && !undirected.directed);
+    }
+
+    #[test]
+    fn test_metrics_empty_and_single_node() {
+        for metrics in [
+            GraphMetrics::compute(0, 0),
+            GraphMetrics::compute(1, 0),
+            GraphMetrics::compute_directed(0, 0),
+            GraphMetrics::compute_directed(1, 0),
+        ] {
+            assert_eq!(metrics.density, 0.0);
+            assert_eq!(metrics.avg_degree, 0.0);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+// tests/multi_tests.rs
+#[cfg(test)]
+mod tests {
+    use limit_benchmark::{
+        classify_intent, Intent, IntentType, MultiIntentHarness, MultiIntentQuery, MultiIntentResult, Relation,
+    };
+    use std::collections::{HashMap, HashSet};
+
+    fn create_test_query(intent_types: Vec<IntentType>) -> MultiIntentQuery {
+        MultiIntentQuery {
+            id: "q1".to_string(),
+            intents: intent_types
+                .into_iter()
+                .map(|intent_type| Intent {
+                    intent_type,
+                    query: "How does spike bind ACE2?".to_string(),
+                    priority: 1.0,
+                    domain: None,
+                })
+                .collect(),
+            context: HashMap::new(),
+        }
+    }
+
+    fn relations(names: &[&str]) -> HashSet<Relation> {
+        names.iter().map(|r| r.to_string()).collect()
+    }
+
+    #[test]
+    fn test_avg_latency_by_intent_type() {
+        let mut harness = MultiIntentHarness::new();
+        harness.add_query(create_test_query(vec![IntentType::Factual, IntentType::Predictive]));
+        harness.add_query(create_test_query(vec![IntentType::Predictive, IntentType::Causal]));
+
+        let summary = harness.run_benchmark(|query| {
+            let intent_latencies_ms: Vec<f64> = query
+                .intents
+                .iter()
+                .map(|i| match i.intent_type {
+                    IntentType::Predictive if query.intents[0].intent_type == IntentType::Predictive => 300.0,
+                    IntentType::Predictive => 200.0,
+                    _ => 20.0,
+                })
+                .collect();
+            MultiIntentResult {
+                query_id: query.id.clone(),
+                success: true,
+                latency_ms: intent_latencies_ms.iter().sum(),
+                intent_coverage: 1.0,
+                provenance: vec![],
+                intent_latencies_ms,
+                backend: String::new(),
+            }
+        });
+
+        assert_eq!(summary.avg_latency_by_type.len(), 3);
+        assert_eq!(summary.avg_latency_by_type[&IntentType::Predictive], 250.0);
+        assert_eq!(summary.avg_latency_by_type[&IntentType::Factual], 20.0);
+        assert_eq!(summary.avg_latency_by_type[&IntentType::Causal], 20.0);
+        assert!(!summary.avg_latency_by_type.contains_key(&IntentType::Comparative));
+        assert_eq!(summary.avg_latency_ms, 270.0);
+    }
+
+    #[test]
+    fn test_summary_per_backend() {
+        let mut harness = MultiIntentHarness::new();
+        for (id, backend) in [("q1", "simulator"), ("q2", "qpu"), ("q3", "simulator"), ("q4", "qpu")] {
+            let mut query = create_test_query(vec![IntentType::Factual]);
+            query.id = id.to_string();
+            query.context.insert("backend".to_string(), backend.to_string());
+            harness.add_query(query);
+        }
+
+        let summary = harness.run_benchmark(|query| {
+            let qpu = query.context["backend"] == "qpu";
+            MultiIntentResult {
+                query_id: query.id.clone(),
+                success: qpu || query.id == "q1",
+                latency_ms: if qpu { 400.0 } else if query.id == "q1" { 100.0 } else { 200.0 },
+                intent_coverage: if qpu { 0.9 } else { 0.6 },
+                provenance: vec![],
+                intent_latencies_ms: vec![],
+                backend: query.context["backend"].clone(),
+            }
+        });
+
+        assert_eq!(summary.by_backend.len(), 2);
+        let simulator = &summary.by_backend["simulator"];
+        assert_eq!(simulator.queries, 2);
+        assert_eq!(simulator.successful_queries, 1);
+        assert_eq!(simulator.avg_latency_ms, 150.0);
+        assert!((simulator.avg_intent_coverage - 0.6).abs() < 1e-6);
+        let qpu = &summary.by_backend["qpu"];
+        assert_eq!(qpu.queries, 2);
+        assert_eq!(qpu.successful_queries, 2);
+        assert_eq!(qpu.avg_latency_ms, 400.0);
+        assert!((qpu.avg_intent_coverage - 0.9).abs() < 1e-6);
+        assert_eq!(summary.avg_latency_ms, 275.0);
+    }
+
+    #[test]
+    fn test_classify_intent() {
+        assert_eq!(classify_intent("How does spike bind?"), IntentType::Causal);
+        assert_eq!(classify_intent("Compare Delta and Omicron"), IntentType::Comparative);
+        assert_eq!(classify_intent("Which variant will dominate next winter?"), IntentType::Predictive);
+        assert_eq!(classify_intent("What is the spike protein?"), IntentType::Factual);
+    }
+
+    #[test]
+    fn test_causal_intent_without_causal_edges() {
+        let query = create_test_query(vec![IntentType::Causal]);
+        assert!(query.expected_relations().contains("binds_to"));
+
+        let graph_relations = relations(&["encodes", "variant_of"]);
+        assert_eq!(query.expected_relation_coverage(&graph_relations), 0.0);
+    }
+
+    #[test]
+    fn test_normalized_priorities() {
+        let mut query = create_test_query(vec![IntentType::Causal, IntentType::Comparative]);
+        query.intents[1].priority = 0.8;
+        let normalized = query.normalized_priorities();
+        assert!((normalized.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+        assert!((normalized[0] - 1.0 / 1.8).abs() < 1e-6);
+        assert!((normalized[1] - 0.8 / 1.8).abs() < 1e-6);
+
+        // Causal is fully covered, Comparative not at all
+        let graph_relations = relations(&["binds_to", "inhibits", "causes"]);
+        assert!((query.weighted_relation_coverage(&graph_relations) - 1.0 / 1.8).abs() < 1e-6);
+
+        for intent in &mut query.intents {
+            intent.priority = 0.0;
+        }
+        assert_eq!(query.normalized_priorities(), vec![0.5, 0.5]);
+        assert!((query.weighted_relation_coverage(&graph_relations) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_expected_relation_coverage() {
+        let query = create_test_query(vec![IntentType::Causal, IntentType::Comparative]);
+        let graph_relations = relations(&["binds_to", "inhibits", "causes", "variant_of"]);
+
+        assert_eq!(query.expected_relations().len(), 5);
+        assert!((query.expected_relation_coverage(&graph_relations) - 0.8).abs() < 1e-6);
+        assert_eq!(
+            create_test_query(vec![IntentType::Exploratory]).expected_relation_coverage(&graph_relations),
+            1.0
+        );
+    }
+}
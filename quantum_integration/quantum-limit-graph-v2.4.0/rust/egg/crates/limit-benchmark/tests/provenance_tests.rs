@@ -0,0 +1,24 @@
+// tests/provenance_tests.rs
+#[cfg(test)]
+mod tests {
+    use limit_benchmark::ProvenanceTracker;
+
+    #[test]
+    fn test_tracker_json_round_trip() {
+        let mut tracker = ProvenanceTracker::new();
+        tracker.record("add_edge".to_string(), "PubMed:12345".to_string(), 0.95);
+        tracker.record("merge".to_string(), "bioRxiv".to_string(), 0.7);
+
+        let compact = tracker.to_json().unwrap();
+        let pretty = tracker.to_json_pretty().unwrap();
+
+        assert!(!compact.contains('\n'));
+        assert!(pretty.contains('\n'));
+        for json in [&compact, &pretty] {
+            let restored: ProvenanceTracker = serde_json::from_str(json).unwrap();
+            let sources: Vec<&str> = restored.get_all_records().iter().map(|r| r.source.as_str()).collect();
+            assert_eq!(sources, vec!["PubMed:12345", "bioRxiv"]);
+            assert_eq!(restored.filter_by_confidence(0.9).len(), 1);
+        }
+    }
+}
@@ -1,7 +1,9 @@
 // crates/limit-bio-sars/src/api.rs
-use axum::{Router, routing::get, extract::State, Json};
+use axum::{Router, routing::get, extract::{Path, State}, http::StatusCode, Json};
 use std::sync::{Arc, Mutex};
+use uuid::Uuid;
 use crate::graph::BioGraph;
+use crate::validation::GraphProblem;
 
 #[derive(Clone)]
 pub struct AppState { pub graphs: Arc<Mutex<Vec<BioGraph>>> }
@@ -10,6 +12,7 @@ pub fn router(state: AppState) -> Router {
     Router::new()
         .route("/bio/graphs", get(list_graphs))
         .route("/bio/graph/counts", get(counts))
+        .route("/bio/graph/:id/validate", get(validate_graph))
         .with_state(state)
 }
 
@@ -31,3 +34,15 @@ async fn counts(State(state): State<AppState>) -> Json<Option<Counts>> {
         edges: x.edges.len(),
     }))
 }
+
+/// Structural problems of the graph with the given id
+async fn validate_graph(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<GraphProblem>>, StatusCode> {
+    let g = state.graphs.lock().unwrap();
+    g.iter()
+        .find(|x| x.id == id)
+        .map(|x| Json(x.validate()))
+        .ok_or(StatusCode::NOT_FOUND)
+}
@@ -0,0 +1,89 @@
+// crates/limit-bio-sars/src/bin/bio-cli.rs
+use anyhow::Result;
+use clap::{Parser, Subcommand, ValueEnum};
+use limit_bio_sars::{BioGraphLoader, ExportOptions};
+use std::path::PathBuf;
+
+/// Offline analysis of SARS-CoV-2 graph dataset directories
+#[derive(Parser)]
+#[command(name = "bio-cli", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print node and edge counts
+    Stats { dir: PathBuf },
+    /// Print soft node warnings and structural graph problems; exits non-zero
+    /// when the graph has any problem
+    Validate { dir: PathBuf },
+    /// Write the graph to stdout in a visualization format
+    Export {
+        #[arg(long, value_enum, default_value_t = ExportFormat::Dot)]
+        format: ExportFormat,
+        /// Only export these node types (virus, protein, receptor, variant, therapy)
+        #[arg(long, value_delimiter = ',')]
+        node_types: Vec<String>,
+        /// Add each edge's sources as a pipe-joined `provenance` attribute
+        #[arg(long)]
+        provenance: bool,
+        dir: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    Dot,
+    Graphml,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let mut loader = BioGraphLoader::new();
+
+    match cli.command {
+        Command::Stats { dir } => {
+            let graph = loader.load_dir(&dir)?;
+            println!("Graph: {}", graph.virus.name);
+            println!("Nodes: {}", graph.node_count());
+            println!("  Proteins: {}", graph.proteins.len());
+            println!("  Receptors: {}", graph.receptors.len());
+            println!("  Variants: {}", graph.variants.len());
+            println!("  Therapies: {}", graph.therapies.len());
+            println!("Edges: {}", graph.edge_count());
+            println!("Warnings: {}", loader.warnings().len());
+        }
+        Command::Validate { dir } => {
+            let graph = loader.load_dir(&dir)?;
+            for warning in loader.warnings() {
+                println!("{} ({}): {}", warning.node_name, warning.node_id, warning.message);
+            }
+            println!("{} warning(s)", loader.warnings().len());
+
+            let problems = graph.validate();
+            for problem in &problems {
+                println!("{}", problem);
+            }
+            println!("{} problem(s)", problems.len());
+            if !problems.is_empty() {
+                std::process::exit(1);
+            }
+        }
+        Command::Export { format, node_types, provenance, dir } => {
+            let graph = loader.load_dir(&dir)?;
+            let node_types: Vec<&str> = node_types.iter().map(String::as_str).collect();
+            let options = ExportOptions {
+                node_types: (!node_types.is_empty()).then_some(node_types.as_slice()),
+                include_provenance: provenance,
+            };
+            match format {
+                ExportFormat::Dot => print!("{}", graph.to_dot_with(&options)),
+                ExportFormat::Graphml => print!("{}", graph.to_graphml_with(&options)),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// crates/limit-bio-sars/src/clustering.rs
+use crate::graph::BioGraph;
+use serde::{Serialize, Deserialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Similarity between two variants' mutation sets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SimilarityMetric {
+    /// |A ∩ B| / |A ∪ B|
+    Jaccard,
+    /// |A ∩ B| / min(|A|, |B|), so a variant nested in another scores 1.0
+    Overlap,
+    /// |A ∩ B| as a raw count (thresholds are then mutation counts)
+    SharedCount,
+}
+
+impl SimilarityMetric {
+    pub fn similarity(&self, a: &[String], b: &[String]) -> f32 {
+        let a: HashSet<&String> = a.iter().collect();
+        let b: HashSet<&String> = b.iter().collect();
+        let shared = a.intersection(&b).count() as f32;
+
+        let denominator = match self {
+            SimilarityMetric::Jaccard => a.union(&b).count(),
+            SimilarityMetric::Overlap => a.len().min(b.len()),
+            SimilarityMetric::SharedCount => return shared,
+        };
+        if denominator == 0 {
+            0.0
+        } else {
+            shared / denominator as f32
+        }
+    }
+}
+
+impl BioGraph {
+    /// Group variants into families by single-linkage agglomeration: two
+    /// variants share a cluster when a chain of pairs with similarity at or
+    /// above `threshold` connects them
+    ///
+    /// Every variant appears in exactly one cluster; clusters and their
+    /// members follow variant insertion order.
+    pub fn cluster_variants(&self, metric: SimilarityMetric, threshold: f32) -> Vec<Vec<Uuid>> {
+        let n = self.variants.len();
+        let mut parent: Vec<usize> = (0..n).collect();
+
+        fn root(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let similarity =
+                    metric.similarity(&self.variants[i].mutations, &self.variants[j].mutations);
+                if similarity >= threshold {
+                    let (ri, rj) = (root(&mut parent, i), root(&mut parent, j));
+                    parent[ri.max(rj)] = ri.min(rj);
+                }
+            }
+        }
+
+        let mut clusters: Vec<(usize, Vec<Uuid>)> = Vec::new();
+        for i in 0..n {
+            let r = root(&mut parent, i);
+            match clusters.iter_mut().find(|(cluster_root, _)| *cluster_root == r) {
+                Some((_, members)) => members.push(self.variants[i].id),
+                None => clusters.push((r, vec![self.variants[i].id])),
+            }
+        }
+        clusters.into_iter().map(|(_, members)| members).collect()
+    }
+}
@@ -0,0 +1,72 @@
+// crates/limit-bio-sars/src/completeness.rs
+use serde::{Serialize, Deserialize};
+
+/// Minimum counts and required node names a graph must meet before publication
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExpectedSchema {
+    pub min_proteins: usize,
+    pub min_receptors: usize,
+    pub min_variants: usize,
+    pub min_therapies: usize,
+    pub min_edges: usize,
+    pub required_proteins: Vec<String>,
+    pub required_receptors: Vec<String>,
+    pub required_variants: Vec<String>,
+    pub required_therapies: Vec<String>,
+}
+
+/// Required node that is absent from the graph
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MissingNode {
+    pub category: String,
+    pub name: String,
+}
+
+/// Category holding fewer entries than the schema expects
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryShortfall {
+    pub category: String,
+    pub expected: usize,
+    pub actual: usize,
+}
+
+/// Result of checking a graph against an `ExpectedSchema`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompletenessReport {
+    pub missing_nodes: Vec<MissingNode>,
+    pub under_populated: Vec<CategoryShortfall>,
+}
+
+impl CompletenessReport {
+    /// True when nothing is missing or under-populated
+    pub fn is_complete(&self) -> bool {
+        self.missing_nodes.is_empty() && self.under_populated.is_empty()
+    }
+
+    pub(crate) fn check_count(&mut self, category: &str, expected: usize, actual: usize) {
+        if actual < expected {
+            self.under_populated.push(CategoryShortfall {
+                category: category.to_string(),
+                expected,
+                actual,
+            });
+        }
+    }
+
+    pub(crate) fn check_required<'a>(
+        &mut self,
+        category: &str,
+        required: &[String],
+        present: impl Iterator<Item = &'a str> + Clone,
+    ) {
+        for name in required {
+            if !present.clone().any(|p| p == name) {
+                self.missing_nodes.push(MissingNode {
+                    category: category.to_string(),
+                    name: name.clone(),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+// crates/limit-bio-sars/src/corpus.rs
+use crate::nodes::BioCorpusDoc;
+use serde::Serialize;
+
+/// Characters of context kept on each side of the first match in a snippet
+pub const SNIPPET_RADIUS: usize = 60;
+
+/// Searchable collection of corpus documents
+#[derive(Debug, Clone, Default)]
+pub struct CorpusIndex {
+    pub docs: Vec<BioCorpusDoc>,
+}
+
+/// A document matching a search, with what's needed to highlight it
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit<'a> {
+    pub doc: &'a BioCorpusDoc,
+    /// The first match with up to `SNIPPET_RADIUS` characters either side
+    pub snippet: String,
+    /// Byte offsets into `doc.text` where each match starts; overlapping
+    /// matches are all reported and every offset is a char boundary
+    pub positions: Vec<usize>,
+}
+
+impl CorpusIndex {
+    pub fn new(docs: Vec<BioCorpusDoc>) -> Self {
+        Self { docs }
+    }
+
+    /// Documents whose text contains `term` (case-insensitively), in corpus
+    /// order; an empty or blank term matches nothing
+    pub fn search(&self, term: &str) -> Vec<SearchHit<'_>> {
+        let term = term.trim();
+        if term.is_empty() {
+            return vec![];
+        }
+
+        self.docs
+            .iter()
+            .filter_map(|doc| {
+                let matches = find_matches(&doc.text, term);
+                let &(start, end) = matches.first()?;
+                Some(SearchHit {
+                    doc,
+                    snippet: snippet_around(&doc.text, start, end),
+                    positions: matches.into_iter().map(|(start, _)| start).collect(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Byte spans of every case-insensitive occurrence of `term` in `text`,
+/// trying each char boundary so overlapping occurrences are found
+fn find_matches(text: &str, term: &str) -> Vec<(usize, usize)> {
+    text.char_indices()
+        .filter_map(|(start, _)| match_len(&text[start..], term).map(|len| (start, start + len)))
+        .collect()
+}
+
+/// Byte length of the prefix of `text` equal to `term` ignoring case
+fn match_len(text: &str, term: &str) -> Option<usize> {
+    let mut expected = term.chars().flat_map(char::to_lowercase).peekable();
+    for (i, c) in text.char_indices() {
+        if expected.peek().is_none() {
+            return Some(i);
+        }
+        for lower in c.to_lowercase() {
+            if expected.next() != Some(lower) {
+                return None;
+            }
+        }
+    }
+    expected.peek().is_none().then_some(text.len())
+}
+
+/// The match spanning bytes `start..end` with up to `SNIPPET_RADIUS`
+/// characters of context either side, cut on char boundaries
+fn snippet_around(text: &str, start: usize, end: usize) -> String {
+    let from = text[..start]
+        .char_indices()
+        .rev()
+        .nth(SNIPPET_RADIUS - 1)
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let to = text[end..]
+        .char_indices()
+        .nth(SNIPPET_RADIUS)
+        .map(|(i, _)| end + i)
+        .unwrap_or(text.len());
+    text[from..to].to_string()
+}
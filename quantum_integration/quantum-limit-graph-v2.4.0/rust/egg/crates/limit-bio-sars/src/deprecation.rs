@@ -0,0 +1,69 @@
+// crates/limit-bio-sars/src/deprecation.rs
+use crate::graph::BioGraph;
+use crate::nodes::{NodeId, VariantNode};
+use anyhow::{bail, Result};
+use serde::{Serialize, Deserialize};
+use uuid::Uuid;
+
+/// Whether queries and metrics consider deprecated nodes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeprecatedNodes {
+    /// Skip deprecated nodes (the default)
+    #[default]
+    Exclude,
+    /// Treat deprecated nodes like any other
+    Include,
+}
+
+impl BioGraph {
+    /// Mark a node obsolete, optionally pointing at the node replacing it
+    /// (e.g. a superseded variant name); the node and its edges are kept for
+    /// provenance
+    ///
+    /// Deprecating an already deprecated node updates its successor.
+    pub fn deprecate_node(&mut self, id: Uuid, superseded_by: Option<Uuid>) -> Result<()> {
+        if self.get_node(NodeId(id)).is_none() {
+            bail!("Unknown node {}", id);
+        }
+        if let Some(successor) = superseded_by {
+            if successor == id {
+                bail!("Node {} cannot supersede itself", id);
+            }
+            if self.get_node(NodeId(successor)).is_none() {
+                bail!("Unknown successor node {}", successor);
+            }
+        }
+        self.deprecated.insert(id, superseded_by);
+        Ok(())
+    }
+
+    pub fn is_deprecated(&self, id: Uuid) -> bool {
+        self.deprecated.contains_key(&id)
+    }
+
+    /// The node that replaced a deprecated one, if recorded
+    pub fn superseded_by(&self, id: Uuid) -> Option<Uuid> {
+        self.deprecated.get(&id).copied().flatten()
+    }
+
+    /// Whether a query run with `deprecated` should skip node `id`
+    pub(crate) fn is_excluded(&self, id: Uuid, deprecated: DeprecatedNodes) -> bool {
+        deprecated == DeprecatedNodes::Exclude && self.is_deprecated(id)
+    }
+
+    /// Variants by descending immune escape; variants without a value come last
+    pub fn rank_variants_by_immune_escape(&self, deprecated: DeprecatedNodes) -> Vec<&VariantNode> {
+        let mut variants: Vec<&VariantNode> = self
+            .variants
+            .iter()
+            .filter(|v| !self.is_excluded(v.id, deprecated))
+            .collect();
+        variants.sort_by(|a, b| {
+            b.immune_escape
+                .unwrap_or(f32::MIN)
+                .partial_cmp(&a.immune_escape.unwrap_or(f32::MIN))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        variants
+    }
+}
@@ -0,0 +1,230 @@
+// crates/limit-bio-sars/src/export.rs
+use crate::deprecation::DeprecatedNodes;
+use crate::graph::BioGraph;
+use crate::nodes::Edge;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Rows shown in each ranked section of the markdown report
+const REPORT_TOP_N: usize = 5;
+
+/// What the DOT and GraphML exporters include
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExportOptions<'a> {
+    /// Restrict the export to these node types (e.g. `["protein", "receptor"]`)
+    /// and the edges between them; `None` exports every node
+    pub node_types: Option<&'a [&'a str]>,
+    /// Add each edge's sources as a pipe-joined `provenance` attribute
+    pub include_provenance: bool,
+}
+
+impl<'a> ExportOptions<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_node_types(mut self, node_types: &'a [&'a str]) -> Self {
+        self.node_types = Some(node_types);
+        self
+    }
+
+    pub fn with_provenance(mut self) -> Self {
+        self.include_provenance = true;
+        self
+    }
+}
+
+impl BioGraph {
+    /// Nodes of the selected types (all when `node_types` is `None`) and the
+    /// edges whose endpoints are both selected
+    fn export_slice(
+        &self,
+        node_types: Option<&[&str]>,
+    ) -> (Vec<(Uuid, &str, &'static str)>, Vec<&Edge>) {
+        let nodes: Vec<_> = self
+            .labelled_nodes()
+            .into_iter()
+            .filter(|(_, _, node_type)| node_types.map_or(true, |types| types.contains(node_type)))
+            .collect();
+        let selected: HashSet<Uuid> = nodes.iter().map(|(id, _, _)| *id).collect();
+        let edges = self
+            .edges
+            .iter()
+            .filter(|e| selected.contains(&e.src) && selected.contains(&e.dst))
+            .collect();
+        (nodes, edges)
+    }
+
+    /// Export as a Graphviz DOT digraph (nodes labelled by name, edges by relation)
+    pub fn to_dot(&self) -> String {
+        self.to_dot_with(&ExportOptions::default())
+    }
+
+    /// DOT export restricted and annotated per `options`
+    pub fn to_dot_with(&self, options: &ExportOptions) -> String {
+        let (nodes, edges) = self.export_slice(options.node_types);
+        let mut out = format!("digraph \"{}\" {{\n", self.id);
+
+        for (id, name, node_type) in nodes {
+            out.push_str(&format!(
+                "  \"{}\" [label=\"{}\", type=\"{}\"];\n",
+                id,
+                dot_escape(name),
+                node_type
+            ));
+        }
+
+        for edge in edges {
+            let provenance = if options.include_provenance {
+                format!(", provenance=\"{}\"", dot_escape(&edge.provenance.join("|")))
+            } else {
+                String::new()
+            };
+            out.push_str(&format!(
+                "  \"{}\" -> \"{}\" [label=\"{}\", weight={}{}];\n",
+                edge.src,
+                edge.dst,
+                dot_escape(&edge.relation),
+                edge.confidence,
+                provenance
+            ));
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Export as GraphML (loadable in Cytoscape and Gephi)
+    ///
+    /// Nodes carry `label` and `type` attributes; edges carry `relation` and
+    /// their confidence as a numeric `weight`.
+    pub fn to_graphml(&self) -> String {
+        self.to_graphml_with(&ExportOptions::default())
+    }
+
+    /// GraphML export restricted and annotated per `options`
+    pub fn to_graphml_with(&self, options: &ExportOptions) -> String {
+        let (nodes, edges) = self.export_slice(options.node_types);
+        let mut out = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n\
+             \x20 <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n\
+             \x20 <key id=\"type\" for=\"node\" attr.name=\"type\" attr.type=\"string\"/>\n\
+             \x20 <key id=\"relation\" for=\"edge\" attr.name=\"relation\" attr.type=\"string\"/>\n\
+             \x20 <key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"double\"/>\n",
+        );
+        if options.include_provenance {
+            out.push_str("  <key id=\"provenance\" for=\"edge\" attr.name=\"provenance\" attr.type=\"string\"/>\n");
+        }
+        out.push_str(&format!(
+            "  <graph id=\"{}\" edgedefault=\"directed\">\n",
+            self.id
+        ));
+
+        for (id, name, node_type) in nodes {
+            out.push_str(&format!(
+                "    <node id=\"{}\"><data key=\"label\">{}</data><data key=\"type\">{}</data></node>\n",
+                id,
+                xml_escape(name),
+                node_type
+            ));
+        }
+
+        for edge in edges {
+            let provenance = if options.include_provenance {
+                format!("<data key=\"provenance\">{}</data>", xml_escape(&edge.provenance.join("|")))
+            } else {
+                String::new()
+            };
+            out.push_str(&format!(
+                "    <edge source=\"{}\" target=\"{}\"><data key=\"relation\">{}</data><data key=\"weight\">{}</data>{}</edge>\n",
+                edge.src,
+                edge.dst,
+                xml_escape(&edge.relation),
+                edge.confidence,
+                provenance
+            ));
+        }
+
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+}
+
+impl BioGraph {
+    /// Human-readable summary for pasting into PRs: node counts, variants by
+    /// immune escape, highest-confidence edges and provenance coverage
+    pub fn to_markdown_report(&self) -> String {
+        let names: HashMap<_, _> = self
+            .labelled_nodes()
+            .into_iter()
+            .map(|(id, name, _)| (id, name))
+            .collect();
+        let mut out = format!("# Graph Report: {}\n\n", self.virus.name);
+
+        out.push_str("## Nodes\n\n");
+        out.push_str(&format!("- Total nodes: {}\n", self.node_count()));
+        out.push_str(&format!("- Proteins: {}\n", self.proteins.len()));
+        out.push_str(&format!("- Receptors: {}\n", self.receptors.len()));
+        out.push_str(&format!("- Variants: {}\n", self.variants.len()));
+        out.push_str(&format!("- Therapies: {}\n", self.therapies.len()));
+        out.push_str(&format!("- Edges: {}\n\n", self.edge_count()));
+
+        out.push_str("## Variants by Immune Escape\n\n");
+        let variants = self.rank_variants_by_immune_escape(DeprecatedNodes::default());
+        for v in variants.iter().take(REPORT_TOP_N) {
+            let escape = v.immune_escape.map_or("n/a".to_string(), |e| format!("{:.2}", e));
+            out.push_str(&format!("- {}: {} ({})\n", v.name, escape, v.mutations.join(", ")));
+        }
+        out.push('\n');
+
+        out.push_str("## Highest-Confidence Edges\n\n");
+        let mut edges: Vec<_> = self.edges.iter().collect();
+        edges.sort_by(|a, b| {
+            b.confidence
+                .partial_cmp(&a.confidence)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        for e in edges.iter().take(REPORT_TOP_N) {
+            out.push_str(&format!(
+                "- {} --{}--> {} ({:.2})\n",
+                names.get(&e.src).copied().unwrap_or("?"),
+                e.relation,
+                names.get(&e.dst).copied().unwrap_or("?"),
+                e.confidence
+            ));
+        }
+        out.push('\n');
+
+        out.push_str("## Provenance Coverage\n\n");
+        let cited = self.edges.iter().filter(|e| !e.provenance.is_empty()).count();
+        let sources: HashSet<&String> = self.edges.iter().flat_map(|e| &e.provenance).collect();
+        let coverage = if self.edges.is_empty() {
+            0.0
+        } else {
+            cited as f32 / self.edges.len() as f32 * 100.0
+        };
+        out.push_str(&format!(
+            "- Edges with provenance: {}/{} ({:.0}%)\n",
+            cited,
+            self.edges.len(),
+            coverage
+        ));
+        out.push_str(&format!("- Distinct sources: {}\n", sources.len()));
+
+        out
+    }
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
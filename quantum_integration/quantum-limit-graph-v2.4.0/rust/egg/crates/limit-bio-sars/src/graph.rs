@@ -1,10 +1,17 @@
 // crates/limit-bio-sars/src/graph.rs
+use crate::completeness::{CompletenessReport, ExpectedSchema};
+use crate::deprecation::DeprecatedNodes;
+use crate::index::NodeIndex;
 use crate::nodes::*;
+use crate::vocabulary::RelationVocabulary;
+use anyhow::{bail, Result};
+use serde::{Serialize, Deserialize};
+use serde_json::json;
 use uuid::Uuid;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Enriched biomedical knowledge graph for SARS-CoV-2
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BioGraph {
     pub id: Uuid,
     pub virus: VirusNode,
@@ -13,7 +20,17 @@ pub struct BioGraph {
     pub variants: Vec<VariantNode>,
     pub therapies: Vec<TherapyNode>,
     pub edges: Vec<Edge>,
+    #[serde(default)]
+    pub hyperedges: Vec<HyperEdge>,
     pub metadata: HashMap<String, String>,
+    #[serde(default)]
+    pub vocabulary: Option<RelationVocabulary>,
+    /// Deprecated node ids, each with the node superseding it if known
+    #[serde(default)]
+    pub deprecated: HashMap<Uuid, Option<Uuid>>,
+    /// Attribute index, present once `index` has been called
+    #[serde(skip)]
+    pub(crate) node_index: Option<NodeIndex>,
 }
 
 impl BioGraph {
@@ -26,27 +43,104 @@ impl BioGraph {
             variants: vec![],
             therapies: vec![],
             edges: vec![],
+            hyperedges: vec![],
             metadata: HashMap::new(),
+            vocabulary: None,
+            deprecated: HashMap::new(),
+            node_index: None,
         }
     }
 
+    /// Restrict edge relations to a controlled vocabulary
+    pub fn with_vocabulary(mut self, vocabulary: RelationVocabulary) -> Self {
+        self.vocabulary = Some(vocabulary);
+        self
+    }
+
     pub fn add_protein(&mut self, p: ProteinNode) {
+        if let Some(index) = &mut self.node_index {
+            index.add_protein(&p, self.proteins.len());
+        }
         self.proteins.push(p);
     }
 
     pub fn add_receptor(&mut self, r: HostReceptorNode) {
+        if let Some(index) = &mut self.node_index {
+            index.add_receptor(&r, self.receptors.len());
+        }
         self.receptors.push(r);
     }
 
     pub fn add_variant(&mut self, v: VariantNode) {
+        if let Some(index) = &mut self.node_index {
+            index.add_variant(&v, self.variants.len());
+        }
         self.variants.push(v);
     }
 
     pub fn add_therapy(&mut self, t: TherapyNode) {
+        if let Some(index) = &mut self.node_index {
+            index.add_therapy(&t, self.therapies.len());
+        }
         self.therapies.push(t);
     }
 
-    pub fn link(&mut self, src: Uuid, dst: Uuid, relation: &str, evidence: Option<String>) {
+    /// Remove a protein and every edge touching it, returning the node
+    pub fn remove_protein(&mut self, id: Uuid) -> Option<ProteinNode> {
+        let position = self.proteins.iter().position(|p| p.id == id)?;
+        let removed = self.proteins.remove(position);
+        self.detach_node(id);
+        Some(removed)
+    }
+
+    /// Remove a receptor and every edge touching it, returning the node
+    pub fn remove_receptor(&mut self, id: Uuid) -> Option<HostReceptorNode> {
+        let position = self.receptors.iter().position(|r| r.id == id)?;
+        let removed = self.receptors.remove(position);
+        self.detach_node(id);
+        Some(removed)
+    }
+
+    /// Remove a variant and every edge touching it, returning the node
+    pub fn remove_variant(&mut self, id: Uuid) -> Option<VariantNode> {
+        let position = self.variants.iter().position(|v| v.id == id)?;
+        let removed = self.variants.remove(position);
+        self.detach_node(id);
+        Some(removed)
+    }
+
+    /// Remove a therapy and every edge touching it, returning the node
+    pub fn remove_therapy(&mut self, id: Uuid) -> Option<TherapyNode> {
+        let position = self.therapies.iter().position(|t| t.id == id)?;
+        let removed = self.therapies.remove(position);
+        self.detach_node(id);
+        Some(removed)
+    }
+
+    /// Drop everything referencing a removed node: incident edges, its
+    /// hyperedge memberships (a hyperedge left with fewer than two distinct
+    /// members is dropped) and its deprecation record (nodes it superseded
+    /// lose their successor). A present index is rebuilt, since removal
+    /// shifts node positions.
+    fn detach_node(&mut self, id: Uuid) {
+        self.edges.retain(|e| e.src != id && e.dst != id);
+        for hyperedge in &mut self.hyperedges {
+            hyperedge.nodes.retain(|&n| n != id);
+        }
+        self.hyperedges.retain(|h| h.nodes.iter().collect::<HashSet<_>>().len() >= 2);
+        self.deprecated.remove(&id);
+        for successor in self.deprecated.values_mut() {
+            if *successor == Some(id) {
+                *successor = None;
+            }
+        }
+        if self.node_index.is_some() {
+            self.index();
+        }
+    }
+
+    pub fn link(&mut self, src: Uuid, dst: Uuid, relation: &str, evidence: Option<String>) -> Result<()> {
+        self.check_relation(relation)?;
         self.edges.push(Edge {
             src,
             dst,
@@ -56,6 +150,7 @@ impl BioGraph {
             provenance: vec![],
             metadata: HashMap::new(),
         });
+        Ok(())
     }
 
     pub fn link_with_confidence(
@@ -66,7 +161,8 @@ impl BioGraph {
         evidence: Option<String>,
         confidence: f32,
         provenance: Vec<String>,
-    ) {
+    ) -> Result<()> {
+        self.check_relation(relation)?;
         self.edges.push(Edge {
             src,
             dst,
@@ -76,6 +172,38 @@ impl BioGraph {
             provenance,
             metadata: HashMap::new(),
         });
+        Ok(())
+    }
+
+    /// Relate two or more distinct nodes at once
+    pub fn add_hyperedge(
+        &mut self,
+        nodes: Vec<Uuid>,
+        relation: &str,
+        confidence: f32,
+        provenance: Vec<String>,
+    ) -> Result<()> {
+        self.check_relation(relation)?;
+        if nodes.iter().collect::<HashSet<_>>().len() < 2 {
+            bail!("Hyperedge '{}' needs at least two distinct nodes", relation);
+        }
+        self.hyperedges.push(HyperEdge {
+            nodes,
+            relation: relation.into(),
+            confidence,
+            provenance,
+        });
+        Ok(())
+    }
+
+    /// Reject relations outside the configured vocabulary, if any
+    fn check_relation(&self, relation: &str) -> Result<()> {
+        if let Some(vocabulary) = &self.vocabulary {
+            if !vocabulary.contains(relation) {
+                bail!("Relation '{}' not in vocabulary", relation);
+            }
+        }
+        Ok(())
     }
 
     /// Find all edges connected to a node
@@ -86,6 +214,54 @@ impl BioGraph {
             .collect()
     }
 
+    /// Find all hyperedges a node is a member of
+    pub fn hyperedges_for_node(&self, node_id: Uuid) -> Vec<&HyperEdge> {
+        self.hyperedges
+            .iter()
+            .filter(|h| h.nodes.contains(&node_id))
+            .collect()
+    }
+
+    /// Nodes sharing a hyperedge with `node_id`, each listed once in order of
+    /// first appearance; hyperedges connect their members pairwise
+    pub fn hyperedge_neighbors(&self, node_id: Uuid) -> Vec<Uuid> {
+        let mut seen = HashSet::new();
+        self.hyperedges_for_node(node_id)
+            .into_iter()
+            .flat_map(|h| h.pairs())
+            .filter_map(|(a, b)| match (a == node_id, b == node_id) {
+                (true, false) => Some(b),
+                (false, true) => Some(a),
+                _ => None,
+            })
+            .filter(|id| seen.insert(*id))
+            .collect()
+    }
+
+    /// Importance of a node: the sum of its incident edges' confidences, each
+    /// weighted by the number of distinct provenance sources backing it
+    /// (0.0 for isolated nodes)
+    pub fn node_evidence_score(&self, id: Uuid) -> f32 {
+        self.edges_for_node(id)
+            .iter()
+            .map(|e| e.confidence * e.provenance.iter().collect::<HashSet<_>>().len() as f32)
+            .sum()
+    }
+
+    /// Find edges whose confidence lies within `[min, max]`
+    ///
+    /// Fails if `min > max` or either bound is NaN.
+    pub fn edges_in_confidence_range(&self, min: f32, max: f32) -> Result<Vec<&Edge>> {
+        if min.is_nan() || max.is_nan() || min > max {
+            bail!("Invalid confidence range [{}, {}]", min, max);
+        }
+        Ok(self
+            .edges
+            .iter()
+            .filter(|e| e.confidence >= min && e.confidence <= max)
+            .collect())
+    }
+
     /// Get node count
     pub fn node_count(&self) -> usize {
         1 + self.proteins.len()
@@ -94,23 +270,222 @@ impl BioGraph {
             + self.therapies.len()
     }
 
+    /// Every node as (id, name, node type), virus first
+    pub fn labelled_nodes(&self) -> Vec<(Uuid, &str, &'static str)> {
+        let mut nodes = vec![(self.virus.id, self.virus.name.as_str(), "virus")];
+        nodes.extend(self.proteins.iter().map(|p| (p.id, p.name.as_str(), "protein")));
+        nodes.extend(self.receptors.iter().map(|r| (r.id, r.name.as_str(), "receptor")));
+        nodes.extend(self.variants.iter().map(|v| (v.id, v.name.as_str(), "variant")));
+        nodes.extend(self.therapies.iter().map(|t| (t.id, t.name.as_str(), "therapy")));
+        nodes
+    }
+
+    /// Look up a node by id, returning its name and node type
+    pub fn get_node(&self, id: NodeId) -> Option<(&str, &'static str)> {
+        self.labelled_nodes()
+            .into_iter()
+            .find(|(node_id, _, _)| *node_id == id.0)
+            .map(|(_, name, node_type)| (name, node_type))
+    }
+
+    /// Look up a node of any type by id, including the virus node
+    pub fn get_node_by_id(&self, id: Uuid) -> Option<BioNode> {
+        if self.virus.id == id {
+            return Some(BioNode::Virus(self.virus.clone()));
+        }
+        self.proteins
+            .iter()
+            .find(|p| p.id == id)
+            .map(|p| BioNode::Protein(p.clone()))
+            .or_else(|| self.receptors.iter().find(|r| r.id == id).map(|r| BioNode::Receptor(r.clone())))
+            .or_else(|| self.variants.iter().find(|v| v.id == id).map(|v| BioNode::Variant(v.clone())))
+            .or_else(|| self.therapies.iter().find(|t| t.id == id).map(|t| BioNode::Therapy(t.clone())))
+    }
+
     /// Get edge count
     pub fn edge_count(&self) -> usize {
         self.edges.len()
     }
 
-    /// Find protein by name
+    /// Find protein by name (O(1) once indexed; a stale index entry falls
+    /// back to a scan)
     pub fn find_protein(&self, name: &str) -> Option<&ProteinNode> {
-        self.proteins.iter().find(|p| p.name == name)
+        self.node_index
+            .as_ref()
+            .and_then(|index| index.position_of(name, "protein"))
+            .and_then(|i| self.proteins.get(i))
+            .filter(|p| p.name == name)
+            .or_else(|| self.proteins.iter().find(|p| p.name == name))
     }
 
-    /// Find variant by name
+    /// Find variant by name (O(1) once indexed; a stale index entry falls
+    /// back to a scan)
     pub fn find_variant(&self, name: &str) -> Option<&VariantNode> {
-        self.variants.iter().find(|v| v.name == name)
+        self.node_index
+            .as_ref()
+            .and_then(|index| index.position_of(name, "variant"))
+            .and_then(|i| self.variants.get(i))
+            .filter(|v| v.name == name)
+            .or_else(|| self.variants.iter().find(|v| v.name == name))
+    }
+
+    /// Variants whose Pango lineage starts with `prefix` (case-insensitive),
+    /// e.g. "BA." for Omicron sublineages; variants without a lineage are skipped
+    pub fn variants_by_lineage_prefix(&self, prefix: &str) -> Vec<&VariantNode> {
+        let prefix = prefix.to_lowercase();
+        self.variants
+            .iter()
+            .filter(|v| {
+                v.lineage
+                    .as_ref()
+                    .is_some_and(|lineage| lineage.to_lowercase().starts_with(&prefix))
+            })
+            .collect()
     }
 
-    /// Find therapy by name
+    /// Find therapy by name (O(1) once indexed; a stale index entry falls
+    /// back to a scan)
     pub fn find_therapy(&self, name: &str) -> Option<&TherapyNode> {
-        self.therapies.iter().find(|t| t.name == name)
+        self.node_index
+            .as_ref()
+            .and_then(|index| index.position_of(name, "therapy"))
+            .and_then(|i| self.therapies.get(i))
+            .filter(|t| t.name == name)
+            .or_else(|| self.therapies.iter().find(|t| t.name == name))
+    }
+
+    /// Report required nodes and categories the graph is still missing
+    ///
+    /// Excluded deprecated nodes neither count towards the minimums nor
+    /// satisfy a required name, and neither do edges touching them.
+    pub fn completeness_report(&self, expected: &ExpectedSchema, deprecated: DeprecatedNodes) -> CompletenessReport {
+        let mut report = CompletenessReport::default();
+        let kept = |id: Uuid| !self.is_excluded(id, deprecated);
+        let proteins = self.proteins.iter().filter(|p| kept(p.id));
+        let receptors = self.receptors.iter().filter(|r| kept(r.id));
+        let variants = self.variants.iter().filter(|v| kept(v.id));
+        let therapies = self.therapies.iter().filter(|t| kept(t.id));
+        let edges = self.edges.iter().filter(|e| kept(e.src) && kept(e.dst));
+
+        report.check_count("protein", expected.min_proteins, proteins.clone().count());
+        report.check_count("receptor", expected.min_receptors, receptors.clone().count());
+        report.check_count("variant", expected.min_variants, variants.clone().count());
+        report.check_count("therapy", expected.min_therapies, therapies.clone().count());
+        report.check_count("edge", expected.min_edges, edges.count());
+
+        report.check_required(
+            "protein",
+            &expected.required_proteins,
+            proteins.map(|p| p.name.as_str()),
+        );
+        report.check_required(
+            "receptor",
+            &expected.required_receptors,
+            receptors.map(|r| r.name.as_str()),
+        );
+        report.check_required(
+            "variant",
+            &expected.required_variants,
+            variants.map(|v| v.name.as_str()),
+        );
+        report.check_required(
+            "therapy",
+            &expected.required_therapies,
+            therapies.map(|t| t.name.as_str()),
+        );
+
+        report
+    }
+
+    /// Serialize the whole graph as compact JSON (for pipelines)
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Serialize the whole graph as indented JSON (for human review)
+    pub fn to_json_pretty(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Rebuild a graph from `to_json`/`to_json_pretty` output (unindexed;
+    /// call `index` again if needed)
+    pub fn from_json(s: &str) -> Result<Self> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    /// Serialize as compact JSON that is identical for logically equal graphs,
+    /// whatever the insertion order (for golden-file comparisons)
+    ///
+    /// Nodes are sorted by id, edges by `(src, dst, relation)`, hyperedges by
+    /// `(relation, nodes)` and every map, metadata included, by key.
+    pub fn to_canonical_json(&self) -> Result<String> {
+        let mut graph = self.clone();
+        graph.proteins.sort_by_key(|p| p.id);
+        graph.receptors.sort_by_key(|r| r.id);
+        graph.variants.sort_by_key(|v| v.id);
+        graph.therapies.sort_by_key(|t| t.id);
+        graph.edges.sort_by(|a, b| {
+            (a.src, a.dst, &a.relation).cmp(&(b.src, b.dst, &b.relation))
+        });
+        graph.hyperedges.sort_by(|a, b| (&a.relation, &a.nodes).cmp(&(&b.relation, &b.nodes)));
+        Ok(serde_json::to_string(&canonicalize(serde_json::to_value(&graph)?))?)
+    }
+
+    /// Export as NetworkX node-link JSON (loadable via `networkx.node_link_graph`)
+    pub fn to_node_link_json(&self) -> serde_json::Value {
+        let mut nodes = vec![node_link_entry("virus", &self.virus)];
+        nodes.extend(self.proteins.iter().map(|p| node_link_entry("protein", p)));
+        nodes.extend(self.receptors.iter().map(|r| node_link_entry("receptor", r)));
+        nodes.extend(self.variants.iter().map(|v| node_link_entry("variant", v)));
+        nodes.extend(self.therapies.iter().map(|t| node_link_entry("therapy", t)));
+
+        let links: Vec<serde_json::Value> = self
+            .edges
+            .iter()
+            .map(|e| {
+                json!({
+                    "source": e.src,
+                    "target": e.dst,
+                    "relation": e.relation,
+                    "confidence": e.confidence,
+                    "evidence": e.evidence,
+                    "provenance": e.provenance,
+                })
+            })
+            .collect();
+
+        json!({
+            "directed": true,
+            "multigraph": true,
+            "graph": { "id": self.id, "metadata": self.metadata },
+            "nodes": nodes,
+            "links": links,
+        })
+    }
+}
+
+/// Rebuild every JSON object with its keys in sorted order
+fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let sorted: BTreeMap<String, serde_json::Value> = map
+                .into_iter()
+                .map(|(key, value)| (key, canonicalize(value)))
+                .collect();
+            serde_json::Value::Object(sorted.into_iter().collect())
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.into_iter().map(canonicalize).collect())
+        }
+        other => other,
+    }
+}
+
+/// Serialize a node's attributes and tag it with its node type
+fn node_link_entry<T: Serialize>(node_type: &str, node: &T) -> serde_json::Value {
+    let mut value = serde_json::to_value(node).unwrap_or_default();
+    if let Some(attrs) = value.as_object_mut() {
+        attrs.insert("type".to_string(), json!(node_type));
     }
+    value
 }
@@ -0,0 +1,134 @@
+// crates/limit-bio-sars/src/index.rs
+use crate::graph::BioGraph;
+use crate::nodes::*;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Numeric node attributes available to `nodes_in_range`
+pub const INDEXED_FIELDS: [&str; 4] = ["transmissibility", "immune_escape", "efficacy", "expression_level"];
+
+/// Secondary index over node attributes, built by [`BioGraph::index`]
+#[derive(Debug, Clone, Default)]
+pub struct NodeIndex {
+    /// Node ids by exact name
+    by_name: HashMap<String, Vec<Uuid>>,
+    /// Node type and position within its collection, by id
+    positions: HashMap<Uuid, (&'static str, usize)>,
+    /// `(value, id)` pairs sorted by value, per numeric field
+    numeric: HashMap<&'static str, Vec<(f32, Uuid)>>,
+}
+
+impl NodeIndex {
+    fn insert_node(&mut self, id: Uuid, name: &str, node_type: &'static str, position: usize) {
+        self.by_name.entry(name.to_string()).or_default().push(id);
+        self.positions.insert(id, (node_type, position));
+    }
+
+    /// Keep the field's values sorted; missing and NaN values are not indexed
+    fn insert_value(&mut self, field: &'static str, value: Option<f32>, id: Uuid) {
+        if let Some(value) = value.filter(|v| !v.is_nan()) {
+            let values = self.numeric.entry(field).or_default();
+            let at = values.partition_point(|(v, _)| *v <= value);
+            values.insert(at, (value, id));
+        }
+    }
+
+    pub(crate) fn add_protein(&mut self, p: &ProteinNode, position: usize) {
+        self.insert_node(p.id, &p.name, "protein", position);
+    }
+
+    pub(crate) fn add_receptor(&mut self, r: &HostReceptorNode, position: usize) {
+        self.insert_node(r.id, &r.name, "receptor", position);
+        self.insert_value("expression_level", r.expression_level, r.id);
+    }
+
+    pub(crate) fn add_variant(&mut self, v: &VariantNode, position: usize) {
+        self.insert_node(v.id, &v.name, "variant", position);
+        self.insert_value("transmissibility", v.transmissibility, v.id);
+        self.insert_value("immune_escape", v.immune_escape, v.id);
+    }
+
+    pub(crate) fn add_therapy(&mut self, t: &TherapyNode, position: usize) {
+        self.insert_node(t.id, &t.name, "therapy", position);
+        self.insert_value("efficacy", t.efficacy, t.id);
+    }
+
+    /// Position of the first node of `node_type` named `name`
+    pub(crate) fn position_of(&self, name: &str, node_type: &str) -> Option<usize> {
+        self.by_name
+            .get(name)?
+            .iter()
+            .filter_map(|id| self.positions.get(id))
+            .find(|(t, _)| *t == node_type)
+            .map(|(_, position)| *position)
+    }
+}
+
+impl BioGraph {
+    /// Build (or rebuild) the attribute index, making name lookups O(1) and
+    /// range queries binary searches
+    ///
+    /// The `add_*` methods keep the index current afterwards; assigning the
+    /// node vectors directly bypasses it, so call `index` again after doing so.
+    pub fn index(&mut self) {
+        let mut index = NodeIndex::default();
+        index.insert_node(self.virus.id, &self.virus.name, "virus", 0);
+        for (position, p) in self.proteins.iter().enumerate() {
+            index.add_protein(p, position);
+        }
+        for (position, r) in self.receptors.iter().enumerate() {
+            index.add_receptor(r, position);
+        }
+        for (position, v) in self.variants.iter().enumerate() {
+            index.add_variant(v, position);
+        }
+        for (position, t) in self.therapies.iter().enumerate() {
+            index.add_therapy(t, position);
+        }
+        self.node_index = Some(index);
+    }
+
+    pub fn is_indexed(&self) -> bool {
+        self.node_index.is_some()
+    }
+
+    /// Ids of every node named exactly `name`
+    pub fn find_by_name(&self, name: &str) -> Vec<Uuid> {
+        match &self.node_index {
+            Some(index) => index.by_name.get(name).cloned().unwrap_or_default(),
+            None => self
+                .labelled_nodes()
+                .into_iter()
+                .filter(|(_, n, _)| *n == name)
+                .map(|(id, _, _)| id)
+                .collect(),
+        }
+    }
+
+    /// Ids of nodes whose numeric `field` (one of [`INDEXED_FIELDS`]) lies in
+    /// `[min, max]`, in ascending order of value
+    pub fn nodes_in_range(&self, field: &str, min: f32, max: f32) -> Vec<Uuid> {
+        if let Some(index) = &self.node_index {
+            let Some(values) = index.numeric.get(field) else {
+                return vec![];
+            };
+            let start = values.partition_point(|(v, _)| *v < min);
+            let end = values.partition_point(|(v, _)| *v <= max);
+            return values[start..end.max(start)].iter().map(|(_, id)| *id).collect();
+        }
+
+        let mut values: Vec<(f32, Uuid)> = match field {
+            "expression_level" => self.receptors.iter().map(|r| (r.expression_level, r.id)).collect(),
+            "transmissibility" => self.variants.iter().map(|v| (v.transmissibility, v.id)).collect(),
+            "immune_escape" => self.variants.iter().map(|v| (v.immune_escape, v.id)).collect(),
+            "efficacy" => self.therapies.iter().map(|t| (t.efficacy, t.id)).collect(),
+            _ => vec![],
+        }
+        .into_iter()
+        .filter_map(|(value, id)| value.map(|v| (v, id)))
+        .filter(|(v, _)| (min..=max).contains(v))
+        .collect();
+        values.sort_by(|a, b| a.0.total_cmp(&b.0));
+        values.into_iter().map(|(_, id)| id).collect()
+    }
+}
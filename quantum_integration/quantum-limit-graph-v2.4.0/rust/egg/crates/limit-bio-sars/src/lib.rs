@@ -2,10 +2,38 @@
 pub mod nodes;
 pub mod graph;
 pub mod loader;
+pub mod vocabulary;
+pub mod validation;
+pub mod completeness;
+pub mod deprecation;
+pub mod export;
+pub mod index;
+pub mod orphans;
+pub mod paths;
+pub mod neighborhood;
+pub mod provenance;
+pub mod clustering;
+pub mod corpus;
 pub mod api;
 
 pub use nodes::{
-    VirusNode, ProteinNode, HostReceptorNode, VariantNode, TherapyNode, Edge, BioCorpusDoc,
+    VirusNode, ProteinNode, HostReceptorNode, VariantNode, TherapyNode, Edge, HyperEdge, BioCorpusDoc,
+    NodeId, BioNode,
 };
 pub use graph::BioGraph;
-pub use loader::{BioGraphLoader, LoaderStats};
+pub use loader::{BioGraphLoader, ConfidencePolicy, LoaderStats};
+pub use vocabulary::{Relation, RelationVocabulary};
+pub use validation::{validate_nodes, GraphProblem, NodeInvariants, NodeWarning};
+pub use export::ExportOptions;
+pub use paths::HopExplanation;
+pub use neighborhood::EgoSubgraph;
+pub use index::{NodeIndex, INDEXED_FIELDS};
+pub use orphans::LinkSuggestion;
+pub use clustering::SimilarityMetric;
+pub use corpus::{CorpusIndex, SearchHit, SNIPPET_RADIUS};
+pub use provenance::{
+    dedup_provenance, merge_provenance, CitingEdge, EdgeImpact, HyperEdgeImpact, ProvenanceEntry, RetractionReport,
+    RETRACTION_CONFIDENCE_THRESHOLD,
+};
+pub use deprecation::DeprecatedNodes;
+pub use completeness::{CategoryShortfall, CompletenessReport, ExpectedSchema, MissingNode};
@@ -1,14 +1,37 @@
 // crates/limit-bio-sars/src/loader.rs
+use crate::graph::BioGraph;
 use crate::nodes::*;
-use anyhow::{Result, Context};
+use crate::validation::{validate_nodes, NodeWarning};
+use anyhow::{bail, Result, Context};
+use flate2::read::GzDecoder;
 use serde_json;
 use std::fs;
-use std::path::Path;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// How `load_edges` treats edge confidences outside `[0, 1]`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConfidencePolicy {
+    /// Load confidences as given
+    #[default]
+    Accept,
+    /// Fail the load, listing every offending edge
+    Strict,
+    /// Pin confidences into `[0, 1]` (NaN becomes 0) and record each
+    /// adjustment in `edge_warnings`
+    Clamp,
+}
 
 /// Loader for biomedical graph nodes from various sources
+///
+/// Paths ending in `.gz` are gzip-decompressed before parsing.
 pub struct BioGraphLoader {
     pub nodes_loaded: usize,
     pub edges_loaded: usize,
+    pub warnings: Vec<NodeWarning>,
+    pub confidence_policy: ConfidencePolicy,
+    /// Edges whose confidence was clamped, described for review
+    pub edge_warnings: Vec<String>,
 }
 
 impl BioGraphLoader {
@@ -16,72 +39,155 @@ impl BioGraphLoader {
         Self {
             nodes_loaded: 0,
             edges_loaded: 0,
+            warnings: vec![],
+            confidence_policy: ConfidencePolicy::Accept,
+            edge_warnings: vec![],
         }
     }
 
+    /// Reject or clamp out-of-range edge confidences from untrusted files
+    pub fn with_confidence_policy(mut self, policy: ConfidencePolicy) -> Self {
+        self.confidence_policy = policy;
+        self
+    }
+
     /// Load virus nodes from JSON file
     pub fn load_virus_nodes(&mut self, path: &Path) -> Result<Vec<VirusNode>> {
-        let content = fs::read_to_string(path)
+        let content = read_content(path)
             .context("Failed to read virus nodes file")?;
         let nodes: Vec<VirusNode> = serde_json::from_str(&content)
             .context("Failed to parse virus nodes JSON")?;
         self.nodes_loaded += nodes.len();
+        self.warnings.extend(validate_nodes(&nodes));
         Ok(nodes)
     }
 
     /// Load protein nodes from JSON file
     pub fn load_protein_nodes(&mut self, path: &Path) -> Result<Vec<ProteinNode>> {
-        let content = fs::read_to_string(path)
+        let content = read_content(path)
             .context("Failed to read protein nodes file")?;
         let nodes: Vec<ProteinNode> = serde_json::from_str(&content)
             .context("Failed to parse protein nodes JSON")?;
         self.nodes_loaded += nodes.len();
+        self.warnings.extend(validate_nodes(&nodes));
         Ok(nodes)
     }
 
     /// Load receptor nodes from JSON file
     pub fn load_receptor_nodes(&mut self, path: &Path) -> Result<Vec<HostReceptorNode>> {
-        let content = fs::read_to_string(path)
+        let content = read_content(path)
             .context("Failed to read receptor nodes file")?;
         let nodes: Vec<HostReceptorNode> = serde_json::from_str(&content)
             .context("Failed to parse receptor nodes JSON")?;
         self.nodes_loaded += nodes.len();
+        self.warnings.extend(validate_nodes(&nodes));
         Ok(nodes)
     }
 
     /// Load variant nodes from JSON file
     pub fn load_variant_nodes(&mut self, path: &Path) -> Result<Vec<VariantNode>> {
-        let content = fs::read_to_string(path)
+        let content = read_content(path)
             .context("Failed to read variant nodes file")?;
         let nodes: Vec<VariantNode> = serde_json::from_str(&content)
             .context("Failed to parse variant nodes JSON")?;
         self.nodes_loaded += nodes.len();
+        self.warnings.extend(validate_nodes(&nodes));
         Ok(nodes)
     }
 
     /// Load therapy nodes from JSON file
     pub fn load_therapy_nodes(&mut self, path: &Path) -> Result<Vec<TherapyNode>> {
-        let content = fs::read_to_string(path)
+        let content = read_content(path)
             .context("Failed to read therapy nodes file")?;
         let nodes: Vec<TherapyNode> = serde_json::from_str(&content)
             .context("Failed to parse therapy nodes JSON")?;
         self.nodes_loaded += nodes.len();
+        self.warnings.extend(validate_nodes(&nodes));
         Ok(nodes)
     }
 
     /// Load edges from JSON file
     pub fn load_edges(&mut self, path: &Path) -> Result<Vec<Edge>> {
-        let content = fs::read_to_string(path)
+        let content = read_content(path)
             .context("Failed to read edges file")?;
-        let edges: Vec<Edge> = serde_json::from_str(&content)
+        let mut edges: Vec<Edge> = serde_json::from_str(&content)
             .context("Failed to parse edges JSON")?;
+        self.check_confidences(&mut edges)?;
         self.edges_loaded += edges.len();
         Ok(edges)
     }
 
+    /// Apply the confidence policy to freshly parsed edges
+    fn check_confidences(&mut self, edges: &mut [Edge]) -> Result<()> {
+        let offending: Vec<String> = edges
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| !(0.0..=1.0).contains(&e.confidence))
+            .map(|(i, e)| {
+                format!(
+                    "Edge {} ({} -[{}]-> {}) confidence {} outside [0, 1]",
+                    i, e.src, e.relation, e.dst, e.confidence
+                )
+            })
+            .collect();
+
+        match self.confidence_policy {
+            ConfidencePolicy::Accept => {}
+            ConfidencePolicy::Strict if !offending.is_empty() => {
+                bail!("Invalid edge confidences: {}", offending.join("; "))
+            }
+            ConfidencePolicy::Strict => {}
+            ConfidencePolicy::Clamp => {
+                for edge in edges.iter_mut() {
+                    edge.confidence = if edge.confidence.is_nan() {
+                        0.0
+                    } else {
+                        edge.confidence.clamp(0.0, 1.0)
+                    };
+                }
+                self.edge_warnings.extend(offending);
+            }
+        }
+        Ok(())
+    }
+
+    /// Load a dataset directory into a graph
+    ///
+    /// Expects `virus.json` (the first virus becomes the graph root) and
+    /// optionally `proteins.json`, `receptors.json`, `variants.json`,
+    /// `therapies.json` and `edges.json`; each may also be gzipped (`.json.gz`).
+    pub fn load_dir(&mut self, dir: &Path) -> Result<BioGraph> {
+        let virus_path = dataset_file(dir, "virus")
+            .with_context(|| format!("No virus.json in {}", dir.display()))?;
+        let virus = self
+            .load_virus_nodes(&virus_path)?
+            .into_iter()
+            .next()
+            .context("virus.json contains no virus nodes")?;
+        let mut graph = BioGraph::new(virus);
+
+        if let Some(path) = dataset_file(dir, "proteins") {
+            graph.proteins = self.load_protein_nodes(&path)?;
+        }
+        if let Some(path) = dataset_file(dir, "receptors") {
+            graph.receptors = self.load_receptor_nodes(&path)?;
+        }
+        if let Some(path) = dataset_file(dir, "variants") {
+            graph.variants = self.load_variant_nodes(&path)?;
+        }
+        if let Some(path) = dataset_file(dir, "therapies") {
+            graph.therapies = self.load_therapy_nodes(&path)?;
+        }
+        if let Some(path) = dataset_file(dir, "edges") {
+            graph.edges = self.load_edges(&path)?;
+        }
+
+        Ok(graph)
+    }
+
     /// Load corpus documents from JSONL file
     pub fn load_corpus(&self, path: &Path) -> Result<Vec<BioCorpusDoc>> {
-        let content = fs::read_to_string(path)
+        let content = read_content(path)
             .context("Failed to read corpus file")?;
         let docs: Vec<BioCorpusDoc> = content
             .lines()
@@ -92,11 +198,17 @@ impl BioGraphLoader {
         Ok(docs)
     }
 
+    /// Soft invariant warnings collected from all nodes loaded so far
+    pub fn warnings(&self) -> &[NodeWarning] {
+        &self.warnings
+    }
+
     /// Get loading statistics
     pub fn stats(&self) -> LoaderStats {
         LoaderStats {
             nodes_loaded: self.nodes_loaded,
             edges_loaded: self.edges_loaded,
+            warnings: self.warnings.len(),
         }
     }
 }
@@ -105,6 +217,26 @@ impl BioGraphLoader {
 pub struct LoaderStats {
     pub nodes_loaded: usize,
     pub edges_loaded: usize,
+    pub warnings: usize,
+}
+
+/// `<stem>.json` or `<stem>.json.gz` inside a dataset directory, if present
+fn dataset_file(dir: &Path, stem: &str) -> Option<PathBuf> {
+    [format!("{}.json", stem), format!("{}.json.gz", stem)]
+        .into_iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Read a file as text, transparently decompressing `.gz` paths
+fn read_content(path: &Path) -> io::Result<String> {
+    if path.extension().is_some_and(|ext| ext == "gz") {
+        let mut content = String::new();
+        GzDecoder::new(fs::File::open(path)?).read_to_string(&mut content)?;
+        Ok(content)
+    } else {
+        fs::read_to_string(path)
+    }
 }
 
 impl Default for BioGraphLoader {
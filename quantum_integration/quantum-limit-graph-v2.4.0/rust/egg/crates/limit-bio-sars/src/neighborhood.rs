@@ -0,0 +1,89 @@
+// crates/limit-bio-sars/src/neighborhood.rs
+use crate::deprecation::DeprecatedNodes;
+use crate::graph::BioGraph;
+use crate::nodes::{Edge, NodeId};
+use std::collections::{HashSet, VecDeque};
+use uuid::Uuid;
+
+/// Nodes within a number of hops of a center node, with the edges among them
+#[derive(Debug, Clone)]
+pub struct EgoSubgraph<'a> {
+    pub center: Uuid,
+    /// In breadth-first order, starting with the center
+    pub nodes: Vec<Uuid>,
+    /// Every edge whose endpoints are both in `nodes`
+    pub edges: Vec<&'a Edge>,
+}
+
+impl BioGraph {
+    /// Nodes adjacent to `id` in either direction, each with the connecting
+    /// edge, optionally only through edges of one `relation`
+    ///
+    /// The neighbor is the edge's other endpoint (its `src` when `id` is the
+    /// `dst`); a self-loop yields `id` itself, once. Excluded deprecated
+    /// nodes are never returned as neighbors.
+    pub fn neighbors(&self, id: Uuid, relation: Option<&str>, deprecated: DeprecatedNodes) -> Vec<(Uuid, &Edge)> {
+        self.edges_for_node(id)
+            .into_iter()
+            .filter(|e| relation.is_none_or(|relation| e.relation == relation))
+            .map(|e| (if e.src == id { e.dst } else { e.src }, e))
+            .filter(|(neighbor, _)| !self.is_excluded(*neighbor, deprecated))
+            .collect()
+    }
+
+    /// Subgraph induced by the nodes within `hops` undirected hops of `id`;
+    /// `None` when `id` is not a node of the graph or is an excluded
+    /// deprecated node
+    pub fn ego_subgraph(&self, id: Uuid, hops: usize, deprecated: DeprecatedNodes) -> Option<EgoSubgraph<'_>> {
+        self.get_node(NodeId(id))?;
+        if self.is_excluded(id, deprecated) {
+            return None;
+        }
+
+        let mut seen: HashSet<Uuid> = HashSet::from([id]);
+        let mut nodes = vec![id];
+        let mut queue = VecDeque::from([(id, 0)]);
+        while let Some((current, depth)) = queue.pop_front() {
+            if depth == hops {
+                continue;
+            }
+            for (next, _) in self.neighbors(current, None, deprecated) {
+                if seen.insert(next) {
+                    nodes.push(next);
+                    queue.push_back((next, depth + 1));
+                }
+            }
+        }
+
+        let edges = self
+            .edges
+            .iter()
+            .filter(|e| seen.contains(&e.src) && seen.contains(&e.dst))
+            .collect();
+        Some(EgoSubgraph { center: id, nodes, edges })
+    }
+
+    /// Undirected edge density, `2e / (n(n-1))`, of the ego subgraph
+    /// within `hops` of `id`, for spotting densely-studied hotspots
+    ///
+    /// Counts linked pairs of distinct nodes, so parallel edges and self-loops
+    /// never push it above 1.0. 0.0 for unknown, isolated or excluded
+    /// deprecated nodes.
+    pub fn local_density(&self, id: Uuid, hops: usize, deprecated: DeprecatedNodes) -> f32 {
+        self.ego_subgraph(id, hops, deprecated)
+            .map(|ego| {
+                let pairs: HashSet<(Uuid, Uuid)> = ego
+                    .edges
+                    .iter()
+                    .filter(|e| e.src != e.dst)
+                    .map(|e| (e.src.min(e.dst), e.src.max(e.dst)))
+                    .collect();
+                let n = ego.nodes.len();
+                if n < 2 {
+                    return 0.0;
+                }
+                2.0 * pairs.len() as f32 / (n * (n - 1)) as f32
+            })
+            .unwrap_or(0.0)
+    }
+}
@@ -1,7 +1,33 @@
 // crates/limit-bio-sars/src/nodes.rs
+use anyhow::{bail, Result};
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 use std::collections::HashMap;
+use std::fmt;
+
+/// Identifier of a graph node, distinct from hub submission ids; serializes
+/// as a bare UUID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NodeId(pub Uuid);
+
+impl From<Uuid> for NodeId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl From<NodeId> for Uuid {
+    fn from(id: NodeId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for NodeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
 
 /// Enriched virus node with metadata and provenance
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -64,6 +90,50 @@ pub struct TherapyNode {
     pub metadata: HashMap<String, String>,
 }
 
+/// A node of any type, as returned by lookups that don't know the type up front
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "node", rename_all = "snake_case")]
+pub enum BioNode {
+    Virus(VirusNode),
+    Protein(ProteinNode),
+    Receptor(HostReceptorNode),
+    Variant(VariantNode),
+    Therapy(TherapyNode),
+}
+
+impl BioNode {
+    pub fn id(&self) -> Uuid {
+        match self {
+            Self::Virus(v) => v.id,
+            Self::Protein(p) => p.id,
+            Self::Receptor(r) => r.id,
+            Self::Variant(v) => v.id,
+            Self::Therapy(t) => t.id,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Virus(v) => &v.name,
+            Self::Protein(p) => &p.name,
+            Self::Receptor(r) => &r.name,
+            Self::Variant(v) => &v.name,
+            Self::Therapy(t) => &t.name,
+        }
+    }
+
+    /// Node type label, as used by `BioGraph::labelled_nodes`
+    pub fn node_type(&self) -> &'static str {
+        match self {
+            Self::Virus(_) => "virus",
+            Self::Protein(_) => "protein",
+            Self::Receptor(_) => "receptor",
+            Self::Variant(_) => "variant",
+            Self::Therapy(_) => "therapy",
+        }
+    }
+}
+
 /// Enriched edge with provenance and confidence
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Edge {
@@ -76,6 +146,29 @@ pub struct Edge {
     pub metadata: HashMap<String, String>,
 }
 
+/// N-ary relation among two or more nodes (e.g. a therapy targeting a
+/// protein in the context of a variant)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HyperEdge {
+    pub nodes: Vec<Uuid>,
+    pub relation: String,
+    pub confidence: f32,
+    pub provenance: Vec<String>,
+}
+
+impl HyperEdge {
+    /// Every unordered pair of member nodes, as if each were joined by an edge
+    pub fn pairs(&self) -> Vec<(Uuid, Uuid)> {
+        let mut pairs = Vec::new();
+        for (i, a) in self.nodes.iter().enumerate() {
+            for b in &self.nodes[i + 1..] {
+                pairs.push((*a, *b));
+            }
+        }
+        pairs
+    }
+}
+
 /// Biomedical corpus document with enriched metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BioCorpusDoc {
@@ -100,6 +193,18 @@ impl VirusNode {
             provenance: vec![],
         }
     }
+
+    /// Validating constructor for untrusted input: the name must be
+    /// non-blank and `genome_kb` a finite positive size
+    pub fn try_new(name: String, genome_kb: f32) -> Result<Self> {
+        if name.trim().is_empty() {
+            bail!("Virus name must not be empty");
+        }
+        if !genome_kb.is_finite() || genome_kb <= 0.0 {
+            bail!("Virus '{}' genome size must be positive, got {} kb", name, genome_kb);
+        }
+        Ok(Self::new(name, genome_kb))
+    }
 }
 
 impl ProteinNode {
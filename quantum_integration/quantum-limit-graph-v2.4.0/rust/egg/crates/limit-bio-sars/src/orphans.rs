@@ -0,0 +1,125 @@
+// crates/limit-bio-sars/src/orphans.rs
+use crate::graph::BioGraph;
+use crate::nodes::{Edge, HyperEdge};
+use serde::{Serialize, Deserialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Endpoints of `edges` plus every member of `hyperedges`
+pub(crate) fn linked_nodes<'a>(
+    edges: impl IntoIterator<Item = &'a Edge>,
+    hyperedges: impl IntoIterator<Item = &'a HyperEdge>,
+) -> HashSet<Uuid> {
+    edges
+        .into_iter()
+        .flat_map(|e| [e.src, e.dst])
+        .chain(hyperedges.into_iter().flat_map(|h| h.nodes.iter().copied()))
+        .collect()
+}
+
+/// Proposed edge for an orphaned node, for a curator to accept or reject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkSuggestion {
+    pub src: Uuid,
+    pub dst: Uuid,
+    pub relation: String,
+    pub reason: String,
+}
+
+impl BioGraph {
+    /// Nodes other than the virus with no incident edges or hyperedges
+    pub fn orphan_nodes(&self) -> Vec<Uuid> {
+        let linked = linked_nodes(&self.edges, &self.hyperedges);
+        self.labelled_nodes()
+            .into_iter()
+            .skip(1) // virus root
+            .map(|(id, _, _)| id)
+            .filter(|id| !linked.contains(id))
+            .collect()
+    }
+
+    /// Propose links for orphan nodes by name heuristics: an orphan is linked
+    /// to another node when either one's name appears in the other's
+    /// descriptive text (name, role, mechanism, binding sites, tissue)
+    pub fn connect_orphans_suggestion(&self) -> Vec<LinkSuggestion> {
+        let nodes: Vec<(Uuid, &str, &str, String)> = self
+            .labelled_nodes()
+            .into_iter()
+            .map(|(id, name, node_type)| (id, name, node_type, self.descriptive_text(id)))
+            .collect();
+
+        let mut suggestions = Vec::new();
+        for orphan in self.orphan_nodes() {
+            let Some((_, orphan_name, orphan_type, orphan_text)) =
+                nodes.iter().find(|(id, ..)| *id == orphan)
+            else {
+                continue;
+            };
+
+            for (other, other_name, other_type, other_text) in &nodes {
+                if *other == orphan {
+                    continue;
+                }
+                let mentioned = orphan_text.contains(&other_name.to_lowercase())
+                    || other_text.contains(&orphan_name.to_lowercase());
+                if !mentioned {
+                    continue;
+                }
+
+                let forward = relation_for(orphan_type, other_type);
+                let backward = relation_for(other_type, orphan_type);
+                let (src, dst, relation) = match (forward, backward) {
+                    (Some(relation), _) => (orphan, *other, relation),
+                    (None, Some(relation)) => (*other, orphan, relation),
+                    (None, None) => (orphan, *other, "related_to"),
+                };
+                suggestions.push(LinkSuggestion {
+                    src,
+                    dst,
+                    relation: relation.to_string(),
+                    reason: format!("'{}' and '{}' mention each other", orphan_name, other_name),
+                });
+            }
+        }
+        suggestions
+    }
+
+    /// Lowercased name plus free-text fields of a node
+    fn descriptive_text(&self, id: Uuid) -> String {
+        let mut parts: Vec<&str> = vec![];
+        if self.virus.id == id {
+            parts.push(&self.virus.name);
+        }
+        if let Some(p) = self.proteins.iter().find(|p| p.id == id) {
+            parts.push(&p.name);
+            parts.extend(p.role.as_deref());
+            parts.extend(p.binding_sites.iter().map(String::as_str));
+        }
+        if let Some(r) = self.receptors.iter().find(|r| r.id == id) {
+            parts.push(&r.name);
+            parts.extend(r.tissue.as_deref());
+        }
+        if let Some(v) = self.variants.iter().find(|v| v.id == id) {
+            parts.push(&v.name);
+            parts.extend(v.lineage.as_deref());
+        }
+        if let Some(t) = self.therapies.iter().find(|t| t.id == id) {
+            parts.push(&t.name);
+            parts.push(&t.mechanism);
+        }
+        parts.join(" ").to_lowercase()
+    }
+}
+
+/// Conventional relation from a node of `src_type` to one of `dst_type`
+fn relation_for(src_type: &str, dst_type: &str) -> Option<&'static str> {
+    match (src_type, dst_type) {
+        ("protein", "receptor") => Some("binds_to"),
+        ("therapy", "protein") => Some("targets"),
+        ("therapy", "variant") => Some("neutralizes"),
+        ("therapy", "virus") => Some("treats"),
+        ("variant", "virus") => Some("variant_of"),
+        ("virus", "protein") => Some("encodes"),
+        _ => None,
+    }
+}
@@ -0,0 +1,273 @@
+// crates/limit-bio-sars/src/paths.rs
+use crate::deprecation::DeprecatedNodes;
+use crate::graph::BioGraph;
+use crate::nodes::NodeId;
+use crate::vocabulary::Relation;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::fmt;
+use uuid::Uuid;
+
+/// One edge along a path, described for reviewers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HopExplanation {
+    pub from_name: String,
+    pub to_name: String,
+    pub relation: String,
+    pub confidence: f32,
+    pub provenance: Vec<String>,
+    pub evidence: Option<String>,
+}
+
+impl fmt::Display for HopExplanation {
+    /// e.g. `Spike Protein binds_to ACE2 (confidence 0.95; PubMed:12345, Nature:2020)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} (confidence {:.2}",
+            self.from_name, self.relation, self.to_name, self.confidence
+        )?;
+        if !self.provenance.is_empty() {
+            write!(f, "; {}", self.provenance.join(", "))?;
+        }
+        write!(f, ")")?;
+        if let Some(evidence) = &self.evidence {
+            write!(f, ": {}", evidence)?;
+        }
+        Ok(())
+    }
+}
+
+impl BioGraph {
+    /// Shortest directed path from `src` to `dst` that only follows edges whose
+    /// relation is in `allowed_relations`, e.g. a purely mechanistic path from
+    /// spike to infection
+    ///
+    /// The path includes both endpoints; `None` when no such path exists.
+    pub fn constrained_path(
+        &self,
+        src: Uuid,
+        dst: Uuid,
+        allowed_relations: &[Relation],
+    ) -> Option<Vec<Uuid>> {
+        let mut previous: HashMap<Uuid, Uuid> = HashMap::new();
+        let mut queue = VecDeque::from([src]);
+
+        while let Some(current) = queue.pop_front() {
+            if current == dst {
+                return Some(trace_back(&previous, dst));
+            }
+
+            for edge in self.edges.iter().filter(|e| {
+                e.src == current && allowed_relations.iter().any(|r| *r == e.relation)
+            }) {
+                if edge.dst != src && !previous.contains_key(&edge.dst) {
+                    previous.insert(edge.dst, current);
+                    queue.push_back(edge.dst);
+                }
+            }
+        }
+        None
+    }
+
+    /// Fewest-hop path from `from` to `to`, following edges in either
+    /// direction (breadth-first search)
+    ///
+    /// The path includes both endpoints (just `[from]` when `from == to`);
+    /// `None` when the two are disconnected or either endpoint is not in the
+    /// graph. Excluded deprecated nodes are neither passed through nor
+    /// accepted as endpoints.
+    pub fn shortest_path(&self, from: Uuid, to: Uuid, deprecated: DeprecatedNodes) -> Option<Vec<Uuid>> {
+        if self.get_node(NodeId(from)).is_none() || self.get_node(NodeId(to)).is_none() {
+            return None;
+        }
+        if self.is_excluded(from, deprecated) || self.is_excluded(to, deprecated) {
+            return None;
+        }
+        let mut previous: HashMap<Uuid, Uuid> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(current) = queue.pop_front() {
+            if current == to {
+                return Some(trace_back(&previous, to));
+            }
+
+            for (next, _) in self.neighbors(current, None, deprecated) {
+                if next != from && !previous.contains_key(&next) {
+                    previous.insert(next, current);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Cheapest directed path from `src` to `dst` where following an edge
+    /// costs `1 - confidence` (Dijkstra), with the path's total cost
+    ///
+    /// Confidences are clamped into `[0, 1]` (NaN counts as 0) so every cost
+    /// is non-negative. The path includes both endpoints; `None` when `dst`
+    /// is unreachable. Excluded deprecated nodes are skipped as in
+    /// `shortest_path`.
+    pub fn min_cost_path(&self, src: Uuid, dst: Uuid, deprecated: DeprecatedNodes) -> Option<(Vec<Uuid>, f32)> {
+        if self.is_excluded(src, deprecated) || self.is_excluded(dst, deprecated) {
+            return None;
+        }
+        let mut best: HashMap<Uuid, f32> = HashMap::from([(src, 0.0)]);
+        let mut previous: HashMap<Uuid, Uuid> = HashMap::new();
+        let mut frontier = BinaryHeap::from([Frontier { cost: 0.0, node: src }]);
+
+        while let Some(Frontier { cost, node }) = frontier.pop() {
+            if node == dst {
+                return Some((trace_back(&previous, dst), cost));
+            }
+            if best.get(&node).is_some_and(|&known| cost > known) {
+                continue; // stale entry superseded by a cheaper route
+            }
+
+            for edge in self
+                .edges
+                .iter()
+                .filter(|e| e.src == node && !self.is_excluded(e.dst, deprecated))
+            {
+                let next = cost + edge_cost(edge.confidence);
+                if best.get(&edge.dst).map_or(true, |&known| next < known) {
+                    best.insert(edge.dst, next);
+                    previous.insert(edge.dst, node);
+                    frontier.push(Frontier { cost: next, node: edge.dst });
+                }
+            }
+        }
+        None
+    }
+
+    /// Most trusted path from `from` to `to`: the one maximizing the product
+    /// of edge confidences (Dijkstra over `-ln(confidence)`), with that product
+    ///
+    /// Edges are followed in either direction, as in `shortest_path`, so a
+    /// longer chain of confident edges beats a single doubtful one.
+    /// Confidences are clamped into `[0, 1]` and zero-confidence (or NaN)
+    /// edges are never followed. `None` when no such path exists. Excluded
+    /// deprecated nodes are skipped as in `shortest_path`.
+    pub fn best_confidence_path(
+        &self,
+        from: Uuid,
+        to: Uuid,
+        deprecated: DeprecatedNodes,
+    ) -> Option<(Vec<Uuid>, f32)> {
+        if self.is_excluded(from, deprecated) || self.is_excluded(to, deprecated) {
+            return None;
+        }
+        let mut best: HashMap<Uuid, f32> = HashMap::from([(from, 0.0)]);
+        let mut previous: HashMap<Uuid, Uuid> = HashMap::new();
+        let mut frontier = BinaryHeap::from([Frontier { cost: 0.0, node: from }]);
+
+        while let Some(Frontier { cost, node }) = frontier.pop() {
+            if node == to {
+                return Some((trace_back(&previous, to), (-cost).exp()));
+            }
+            if best.get(&node).is_some_and(|&known| cost > known) {
+                continue; // stale entry superseded by a more trusted route
+            }
+
+            for (neighbor, edge) in self.neighbors(node, None, deprecated) {
+                // `edge_cost` is 1 - clamped confidence, with NaN costing 1
+                let confidence = 1.0 - edge_cost(edge.confidence);
+                if confidence == 0.0 {
+                    continue;
+                }
+                let next = cost - confidence.ln();
+                if best.get(&neighbor).map_or(true, |&known| next < known) {
+                    best.insert(neighbor, next);
+                    previous.insert(neighbor, node);
+                    frontier.push(Frontier { cost: next, node: neighbor });
+                }
+            }
+        }
+        None
+    }
+
+    /// Describe each hop of an id path (as returned by `constrained_path`,
+    /// `min_cost_path` or `best_confidence_path`) by the edge joining it
+    ///
+    /// A hop may follow an edge in either direction, preferring the forward
+    /// one and, among parallel edges, the most confident. `None` when any hop
+    /// has no edge between its nodes, so a partial explanation is never
+    /// mistaken for a full one.
+    pub fn explain_path(&self, path: &[Uuid]) -> Option<Vec<HopExplanation>> {
+        let name_of = |id: Uuid| {
+            self.get_node(NodeId(id))
+                .map(|(name, _)| name.to_string())
+                .unwrap_or_else(|| id.to_string())
+        };
+
+        path.windows(2)
+            .map(|hop| {
+                let (from, to) = (hop[0], hop[1]);
+                let strongest = |src: Uuid, dst: Uuid| {
+                    self.edges
+                        .iter()
+                        .filter(|e| e.src == src && e.dst == dst)
+                        .max_by(|a, b| a.confidence.total_cmp(&b.confidence))
+                };
+                let edge = strongest(from, to).or_else(|| strongest(to, from))?;
+                Some(HopExplanation {
+                    from_name: name_of(edge.src),
+                    to_name: name_of(edge.dst),
+                    relation: edge.relation.clone(),
+                    confidence: edge.confidence,
+                    provenance: edge.provenance.clone(),
+                    evidence: edge.evidence.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Cost of traversing an edge of the given confidence, in `[0, 1]`
+fn edge_cost(confidence: f32) -> f32 {
+    if confidence.is_nan() {
+        1.0
+    } else {
+        1.0 - confidence.clamp(0.0, 1.0)
+    }
+}
+
+/// Walk predecessor links back from `dst` to the search root
+fn trace_back(previous: &HashMap<Uuid, Uuid>, dst: Uuid) -> Vec<Uuid> {
+    let mut path = vec![dst];
+    let mut node = dst;
+    while let Some(&prev) = previous.get(&node) {
+        path.push(prev);
+        node = prev;
+    }
+    path.reverse();
+    path
+}
+
+/// Dijkstra queue entry, ordered so the cheapest pops first
+struct Frontier {
+    cost: f32,
+    node: Uuid,
+}
+
+impl PartialEq for Frontier {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Frontier {}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
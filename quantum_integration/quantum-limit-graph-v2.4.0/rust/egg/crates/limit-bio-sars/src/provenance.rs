@@ -0,0 +1,254 @@
+// crates/limit-bio-sars/src/provenance.rs
+use crate::graph::BioGraph;
+use crate::nodes::Edge;
+use crate::orphans::linked_nodes;
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Confidence below which an edge weakened by a retraction is flagged
+pub const RETRACTION_CONFIDENCE_THRESHOLD: f32 = 0.5;
+
+/// A provenance source together with the confidence it was cited at, if known
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProvenanceEntry {
+    pub source: String,
+    #[serde(default)]
+    pub confidence: Option<f32>,
+}
+
+impl ProvenanceEntry {
+    pub fn new(source: impl Into<String>, confidence: Option<f32>) -> Self {
+        Self {
+            source: source.into(),
+            confidence,
+        }
+    }
+
+    /// The edge's sources, each carrying the edge's confidence
+    pub fn from_edge(edge: &Edge) -> Vec<Self> {
+        edge.provenance
+            .iter()
+            .map(|source| Self::new(source.clone(), Some(edge.confidence)))
+            .collect()
+    }
+}
+
+/// Collapse entries citing the same source, keeping the highest-confidence
+/// occurrence; a known confidence beats an unknown one
+///
+/// Sources stay in order of first appearance.
+pub fn dedup_provenance(entries: impl IntoIterator<Item = ProvenanceEntry>) -> Vec<ProvenanceEntry> {
+    let mut deduped: Vec<ProvenanceEntry> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    for entry in entries {
+        match positions.get(&entry.source) {
+            Some(&idx) => {
+                let kept = &mut deduped[idx];
+                let better = match (entry.confidence, kept.confidence) {
+                    (Some(new), Some(old)) => new > old,
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                };
+                if better {
+                    kept.confidence = entry.confidence;
+                }
+            }
+            None => {
+                positions.insert(entry.source.clone(), deduped.len());
+                deduped.push(entry);
+            }
+        }
+    }
+    deduped
+}
+
+/// Merge two provenance lists, deduplicating by source
+pub fn merge_provenance(a: &[ProvenanceEntry], b: &[ProvenanceEntry]) -> Vec<ProvenanceEntry> {
+    dedup_provenance(a.iter().chain(b).cloned())
+}
+
+/// An edge or hyperedge citing a provenance source, as listed by
+/// `provenance_index`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CitingEdge {
+    Edge { src: Uuid, dst: Uuid, relation: String },
+    /// A hyperedge's members, in its order
+    HyperEdge { nodes: Vec<Uuid>, relation: String },
+}
+
+/// How retracting one source would affect a single citing edge
+///
+/// Confidence is assumed to be shared evenly between an edge's distinct
+/// sources, so losing one of n sources scales it by (n - 1) / n.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EdgeImpact {
+    pub src: Uuid,
+    pub dst: Uuid,
+    pub relation: String,
+    pub confidence: f32,
+    pub projected_confidence: f32,
+    pub remaining_sources: usize,
+}
+
+/// How retracting one source would affect a single citing hyperedge, on the
+/// same even-share assumption as `EdgeImpact`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HyperEdgeImpact {
+    pub nodes: Vec<Uuid>,
+    pub relation: String,
+    pub confidence: f32,
+    pub projected_confidence: f32,
+    pub remaining_sources: usize,
+}
+
+/// Consequences of removing a retracted source from the graph
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RetractionReport {
+    pub source: String,
+    /// Every edge citing the source
+    pub affected_edges: Vec<EdgeImpact>,
+    /// Edges left with no provenance at all
+    pub unsupported_edges: Vec<(Uuid, Uuid)>,
+    /// Edges still supported but whose projected confidence falls below
+    /// `RETRACTION_CONFIDENCE_THRESHOLD` (and was not already below it)
+    pub below_threshold: Vec<(Uuid, Uuid)>,
+    /// Every hyperedge citing the source
+    pub affected_hyperedges: Vec<HyperEdgeImpact>,
+    /// Members of hyperedges left with no provenance at all
+    pub unsupported_hyperedges: Vec<Vec<Uuid>>,
+    /// Members of hyperedges falling below the threshold, as for edges
+    pub hyperedges_below_threshold: Vec<Vec<Uuid>>,
+    /// Currently linked nodes whose every edge would become unsupported
+    pub orphaned_nodes: Vec<Uuid>,
+}
+
+impl BioGraph {
+    /// Inverted index from each provenance source to the edges and then
+    /// hyperedges citing it, each in graph order
+    ///
+    /// Each citing edge is listed once even if it cites the source twice.
+    /// Parallel edges between the same nodes are listed separately, matching
+    /// the edges `retraction_impact` reports as affected.
+    pub fn provenance_index(&self) -> HashMap<String, Vec<CitingEdge>> {
+        let mut index: HashMap<String, Vec<CitingEdge>> = HashMap::new();
+        let edges = self.edges.iter().map(|e| {
+            let citing = CitingEdge::Edge {
+                src: e.src,
+                dst: e.dst,
+                relation: e.relation.clone(),
+            };
+            (&e.provenance, citing)
+        });
+        let hyperedges = self.hyperedges.iter().map(|h| {
+            let citing = CitingEdge::HyperEdge {
+                nodes: h.nodes.clone(),
+                relation: h.relation.clone(),
+            };
+            (&h.provenance, citing)
+        });
+        for (provenance, citing) in edges.chain(hyperedges) {
+            let sources: HashSet<&String> = provenance.iter().collect();
+            for source in sources {
+                index.entry(source.clone()).or_default().push(citing.clone());
+            }
+        }
+        index
+    }
+
+    /// Which edges and nodes lose support if `source` is retracted
+    pub fn retraction_impact(&self, source: &str) -> RetractionReport {
+        let mut report = RetractionReport {
+            source: source.to_string(),
+            ..RetractionReport::default()
+        };
+        if !self.provenance_index().contains_key(source) {
+            return report;
+        }
+
+        let mut dropped = vec![false; self.edges.len()];
+        for (idx, edge) in self.edges.iter().enumerate() {
+            let Some((remaining_sources, projected_confidence)) =
+                project_retraction(&edge.provenance, edge.confidence, source)
+            else {
+                continue;
+            };
+
+            if remaining_sources == 0 {
+                dropped[idx] = true;
+                report.unsupported_edges.push((edge.src, edge.dst));
+            } else if falls_below_threshold(edge.confidence, projected_confidence) {
+                report.below_threshold.push((edge.src, edge.dst));
+            }
+
+            report.affected_edges.push(EdgeImpact {
+                src: edge.src,
+                dst: edge.dst,
+                relation: edge.relation.clone(),
+                confidence: edge.confidence,
+                projected_confidence,
+                remaining_sources,
+            });
+        }
+
+        let mut dropped_hyperedges = vec![false; self.hyperedges.len()];
+        for (idx, hyperedge) in self.hyperedges.iter().enumerate() {
+            let Some((remaining_sources, projected_confidence)) =
+                project_retraction(&hyperedge.provenance, hyperedge.confidence, source)
+            else {
+                continue;
+            };
+
+            if remaining_sources == 0 {
+                dropped_hyperedges[idx] = true;
+                report.unsupported_hyperedges.push(hyperedge.nodes.clone());
+            } else if falls_below_threshold(hyperedge.confidence, projected_confidence) {
+                report.hyperedges_below_threshold.push(hyperedge.nodes.clone());
+            }
+
+            report.affected_hyperedges.push(HyperEdgeImpact {
+                nodes: hyperedge.nodes.clone(),
+                relation: hyperedge.relation.clone(),
+                confidence: hyperedge.confidence,
+                projected_confidence,
+                remaining_sources,
+            });
+        }
+
+        // Members of surviving hyperedges stay linked
+        let still_linked = linked_nodes(
+            self.edges.iter().zip(&dropped).filter(|(_, &dropped)| !dropped).map(|(e, _)| e),
+            self.hyperedges
+                .iter()
+                .zip(&dropped_hyperedges)
+                .filter(|(_, &dropped)| !dropped)
+                .map(|(h, _)| h),
+        );
+        let orphans: HashSet<Uuid> = self.orphan_nodes().into_iter().collect();
+        report.orphaned_nodes = self
+            .labelled_nodes()
+            .into_iter()
+            .skip(1) // virus root
+            .map(|(id, _, _)| id)
+            .filter(|id| !orphans.contains(id) && !still_linked.contains(id))
+            .collect();
+
+        report
+    }
+}
+
+/// Sources left and projected confidence once `source` is retracted from a
+/// provenance list citing it; `None` if the list does not cite it
+fn project_retraction(provenance: &[String], confidence: f32, source: &str) -> Option<(usize, f32)> {
+    if !provenance.iter().any(|p| p == source) {
+        return None;
+    }
+    let sources: HashSet<&String> = provenance.iter().collect();
+    let remaining_sources = sources.len() - 1;
+    Some((remaining_sources, confidence * remaining_sources as f32 / sources.len() as f32))
+}
+
+/// Whether a retraction takes a confidence from at or above the threshold to below it
+fn falls_below_threshold(confidence: f32, projected_confidence: f32) -> bool {
+    projected_confidence < RETRACTION_CONFIDENCE_THRESHOLD && confidence >= RETRACTION_CONFIDENCE_THRESHOLD
+}
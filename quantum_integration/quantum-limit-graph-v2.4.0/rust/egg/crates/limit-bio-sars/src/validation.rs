@@ -0,0 +1,226 @@
+// crates/limit-bio-sars/src/validation.rs
+use crate::graph::BioGraph;
+use crate::nodes::*;
+use serde::{Serialize, Deserialize};
+use std::collections::HashSet;
+use std::fmt;
+use uuid::Uuid;
+
+/// Soft invariant violation found on a node; the node is still usable
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeWarning {
+    pub node_id: Uuid,
+    pub node_name: String,
+    pub message: String,
+}
+
+/// Structural problem found by [`BioGraph::validate`]; edges and hyperedges
+/// are referred to by their index in `BioGraph::edges` / `BioGraph::hyperedges`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GraphProblem {
+    /// Edge endpoint that is not a node of the graph
+    DanglingEdge { edge: usize, missing: Uuid },
+    ConfidenceOutOfRange { edge: usize, confidence: f32 },
+    SelfLoop { edge: usize, node: Uuid },
+    /// Hyperedge member that is not a node of the graph
+    DanglingHyperedge { hyperedge: usize, missing: Uuid },
+    HyperedgeConfidenceOutOfRange { hyperedge: usize, confidence: f32 },
+    /// Non-virus node with no incident edges
+    Orphan { node: Uuid },
+}
+
+impl fmt::Display for GraphProblem {
+    /// e.g. `edge 3: dangling endpoint 5f0c8a52-...`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DanglingEdge { edge, missing } => write!(f, "edge {}: dangling endpoint {}", edge, missing),
+            Self::ConfidenceOutOfRange { edge, confidence } => {
+                write!(f, "edge {}: confidence {} outside [0, 1]", edge, confidence)
+            }
+            Self::SelfLoop { edge, node } => write!(f, "edge {}: self-loop on {}", edge, node),
+            Self::DanglingHyperedge { hyperedge, missing } => {
+                write!(f, "hyperedge {}: dangling member {}", hyperedge, missing)
+            }
+            Self::HyperedgeConfidenceOutOfRange { hyperedge, confidence } => {
+                write!(f, "hyperedge {}: confidence {} outside [0, 1]", hyperedge, confidence)
+            }
+            Self::Orphan { node } => write!(f, "node {}: orphan", node),
+        }
+    }
+}
+
+impl BioGraph {
+    /// Check the graph's structure: dangling edges and hyperedge members,
+    /// edge and hyperedge confidences outside `[0, 1]`, self-loops and
+    /// orphan nodes
+    pub fn validate(&self) -> Vec<GraphProblem> {
+        let node_ids: HashSet<Uuid> = self.labelled_nodes().into_iter().map(|(id, _, _)| id).collect();
+        let mut problems = Vec::new();
+
+        for (edge, e) in self.edges.iter().enumerate() {
+            let mut endpoints = vec![e.src];
+            if e.dst != e.src {
+                endpoints.push(e.dst);
+            }
+            for missing in endpoints.into_iter().filter(|id| !node_ids.contains(id)) {
+                problems.push(GraphProblem::DanglingEdge { edge, missing });
+            }
+            if !(0.0..=1.0).contains(&e.confidence) {
+                problems.push(GraphProblem::ConfidenceOutOfRange { edge, confidence: e.confidence });
+            }
+            if e.src == e.dst {
+                problems.push(GraphProblem::SelfLoop { edge, node: e.src });
+            }
+        }
+
+        for (hyperedge, h) in self.hyperedges.iter().enumerate() {
+            let mut reported = HashSet::new();
+            for &missing in &h.nodes {
+                if !node_ids.contains(&missing) && reported.insert(missing) {
+                    problems.push(GraphProblem::DanglingHyperedge { hyperedge, missing });
+                }
+            }
+            if !(0.0..=1.0).contains(&h.confidence) {
+                problems.push(GraphProblem::HyperedgeConfidenceOutOfRange { hyperedge, confidence: h.confidence });
+            }
+        }
+
+        problems.extend(self.orphan_nodes().into_iter().map(|node| GraphProblem::Orphan { node }));
+        problems
+    }
+}
+
+/// Per-node-type soft invariants
+pub trait NodeInvariants {
+    fn node_id(&self) -> Uuid;
+    fn node_name(&self) -> &str;
+    fn invariant_violations(&self) -> Vec<String>;
+}
+
+/// Check every node against its type's soft invariants
+pub fn validate_nodes<T: NodeInvariants>(nodes: &[T]) -> Vec<NodeWarning> {
+    nodes
+        .iter()
+        .flat_map(|node| {
+            node.invariant_violations()
+                .into_iter()
+                .map(move |message| NodeWarning {
+                    node_id: node.node_id(),
+                    node_name: node.node_name().to_string(),
+                    message,
+                })
+        })
+        .collect()
+}
+
+fn check_unit_range(field: &str, value: Option<f32>, violations: &mut Vec<String>) {
+    if let Some(v) = value {
+        if !(0.0..=1.0).contains(&v) {
+            violations.push(format!("{} {} outside [0, 1]", field, v));
+        }
+    }
+}
+
+fn check_name(name: &str, violations: &mut Vec<String>) {
+    if name.trim().is_empty() {
+        violations.push("Name is empty".to_string());
+    }
+}
+
+impl NodeInvariants for VirusNode {
+    fn node_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn node_name(&self) -> &str {
+        &self.name
+    }
+
+    fn invariant_violations(&self) -> Vec<String> {
+        let mut violations = Vec::new();
+        check_name(&self.name, &mut violations);
+        if self.genome_kb <= 0.0 {
+            violations.push(format!("Genome size {} kb is not positive", self.genome_kb));
+        }
+        violations
+    }
+}
+
+impl NodeInvariants for ProteinNode {
+    fn node_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn node_name(&self) -> &str {
+        &self.name
+    }
+
+    fn invariant_violations(&self) -> Vec<String> {
+        let mut violations = Vec::new();
+        check_name(&self.name, &mut violations);
+        violations
+    }
+}
+
+impl NodeInvariants for HostReceptorNode {
+    fn node_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn node_name(&self) -> &str {
+        &self.name
+    }
+
+    fn invariant_violations(&self) -> Vec<String> {
+        let mut violations = Vec::new();
+        check_name(&self.name, &mut violations);
+        if let Some(level) = self.expression_level {
+            if level < 0.0 {
+                violations.push(format!("Expression level {} is negative", level));
+            }
+        }
+        violations
+    }
+}
+
+impl NodeInvariants for VariantNode {
+    fn node_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn node_name(&self) -> &str {
+        &self.name
+    }
+
+    fn invariant_violations(&self) -> Vec<String> {
+        let mut violations = Vec::new();
+        check_name(&self.name, &mut violations);
+        if self.mutations.is_empty() {
+            violations.push("Variant has no mutations".to_string());
+        }
+        check_unit_range("Transmissibility", self.transmissibility, &mut violations);
+        check_unit_range("Immune escape", self.immune_escape, &mut violations);
+        violations
+    }
+}
+
+impl NodeInvariants for TherapyNode {
+    fn node_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn node_name(&self) -> &str {
+        &self.name
+    }
+
+    fn invariant_violations(&self) -> Vec<String> {
+        let mut violations = Vec::new();
+        check_name(&self.name, &mut violations);
+        if self.mechanism.trim().is_empty() {
+            violations.push("Therapy mechanism is empty".to_string());
+        }
+        check_unit_range("Efficacy", self.efficacy, &mut violations);
+        violations
+    }
+}
@@ -0,0 +1,36 @@
+// crates/limit-bio-sars/src/vocabulary.rs
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeSet;
+
+/// Edge relation name, e.g. "binds_to"
+pub type Relation = String;
+
+/// Controlled vocabulary of allowed edge relations
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RelationVocabulary {
+    relations: BTreeSet<String>,
+}
+
+impl RelationVocabulary {
+    pub fn new<I, S>(relations: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            relations: relations.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn add(&mut self, relation: &str) {
+        self.relations.insert(relation.to_string());
+    }
+
+    pub fn contains(&self, relation: &str) -> bool {
+        self.relations.contains(relation)
+    }
+
+    pub fn relations(&self) -> impl Iterator<Item = &str> {
+        self.relations.iter().map(String::as_str)
+    }
+}
@@ -0,0 +1,77 @@
+// tests/api_tests.rs
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use limit_bio_sars::api::{router, AppState};
+    use limit_bio_sars::{BioGraph, HostReceptorNode, ProteinNode, VirusNode};
+    use serde_json::Value;
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    async fn get(app: &Router, uri: &str) -> (StatusCode, Value) {
+        let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+    }
+
+    #[tokio::test]
+    async fn test_validate_reports_dangling_edge() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        let missing = Uuid::new_v4();
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph.link(spike.id, ace2.id, "binds_to", None).unwrap();
+        graph.link(spike.id, missing, "binds_to", None).unwrap();
+
+        let graph_id = graph.id;
+        let app = router(AppState { graphs: Arc::new(Mutex::new(vec![graph])) });
+
+        let (status, problems) = get(&app, &format!("/bio/graph/{}/validate", graph_id)).await;
+        assert_eq!(status, StatusCode::OK);
+        let problems = problems.as_array().unwrap();
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0]["kind"], "dangling_edge");
+        assert_eq!(problems[0]["edge"], 1);
+        assert_eq!(problems[0]["missing"], missing.to_string());
+
+        let (status, _) = get(&app, &format!("/bio/graph/{}/validate", Uuid::new_v4())).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_validate_reports_hyperedge_problems() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        let missing = Uuid::new_v4();
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph.add_hyperedge(vec![spike.id, ace2.id], "binds_in_context", 1.5, vec![]).unwrap();
+        graph
+            .add_hyperedge(vec![spike.id, missing, missing], "binds_in_context", 0.7, vec![])
+            .unwrap();
+
+        let graph_id = graph.id;
+        let app = router(AppState { graphs: Arc::new(Mutex::new(vec![graph])) });
+
+        let (status, problems) = get(&app, &format!("/bio/graph/{}/validate", graph_id)).await;
+        assert_eq!(status, StatusCode::OK);
+        let problems = problems.as_array().unwrap();
+        assert_eq!(problems.len(), 2);
+        assert_eq!(problems[0]["kind"], "hyperedge_confidence_out_of_range");
+        assert_eq!(problems[0]["hyperedge"], 0);
+        assert_eq!(problems[1]["kind"], "dangling_hyperedge");
+        assert_eq!(problems[1]["hyperedge"], 1);
+        assert_eq!(problems[1]["missing"], missing.to_string());
+    }
+}
@@ -0,0 +1,58 @@
+// tests/cli_tests.rs
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::process::Command;
+
+    fn fixture_dir() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/dataset")
+    }
+
+    fn run_cli(args: &[&str]) -> String {
+        let output = Command::new(env!("CARGO_BIN_EXE_bio-cli"))
+            .args(args)
+            .output()
+            .expect("bio-cli runs");
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    #[test]
+    fn test_stats_subcommand() {
+        let dir = fixture_dir();
+        let stdout = run_cli(&["stats", dir.to_str().unwrap()]);
+
+        assert!(stdout.contains("Graph: SARS-CoV-2"));
+        assert!(stdout.contains("Nodes: 4"));
+        assert!(stdout.contains("Edges: 1"));
+    }
+
+    #[test]
+    fn test_export_dot_subcommand() {
+        let dir = fixture_dir();
+        let stdout = run_cli(&["export", "--format", "dot", dir.to_str().unwrap()]);
+
+        assert!(stdout.starts_with("digraph"));
+        assert!(stdout.contains("label=\"Spike Protein\""));
+        assert!(stdout.contains("label=\"binds_to\""));
+
+        let proteins_only = run_cli(&["export", "--node-types", "protein", dir.to_str().unwrap()]);
+        assert!(proteins_only.contains("label=\"Spike Protein\""));
+        assert!(!proteins_only.contains("label=\"ACE2\""));
+    }
+
+    #[test]
+    fn test_validate_subcommand_fails_on_dangling_edge() {
+        // Spike binds an ACE2 receptor the dataset never defines
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/dangling");
+        let output = Command::new(env!("CARGO_BIN_EXE_bio-cli"))
+            .args(["validate", dir.to_str().unwrap()])
+            .output()
+            .expect("bio-cli runs");
+        let stdout = String::from_utf8(output.stdout).unwrap();
+
+        assert!(!output.status.success());
+        assert!(stdout.contains("edge 0: dangling endpoint 5f0c8a52-1b7e-4c1a-9d3e-000000000004"));
+        assert!(stdout.contains("1 problem(s)"));
+    }
+}
@@ -0,0 +1,44 @@
+// tests/corpus_tests.rs
+#[cfg(test)]
+mod tests {
+    use limit_bio_sars::{BioCorpusDoc, CorpusIndex, SNIPPET_RADIUS};
+
+    fn doc(source: &str, text: &str) -> BioCorpusDoc {
+        BioCorpusDoc {
+            source: source.to_string(),
+            text: text.to_string(),
+            domain: "Virology".to_string(),
+            authors: vec![],
+            publication_date: None,
+            citations: vec![],
+            keywords: vec![],
+        }
+    }
+
+    #[test]
+    fn test_search_highlights_matches() {
+        let filler = "é".repeat(SNIPPET_RADIUS * 2);
+        let corpus = CorpusIndex::new(vec![
+            doc("doi:1", &format!("{filler} The Spike protein binds ACE2; spike cleavage follows. {filler}")),
+            doc("doi:2", "Nucleocapsid packages the genome."),
+            doc("doi:3", "aaa"),
+        ]);
+
+        let hits = corpus.search("spike");
+        assert_eq!(hits.len(), 1);
+        let hit = &hits[0];
+        assert_eq!(hit.doc.source, "doi:1");
+        assert_eq!(hit.positions.len(), 2);
+        assert!(hit.snippet.contains("Spike"));
+        assert!(hit.snippet.chars().count() < hit.doc.text.chars().count());
+        for &position in &hit.positions {
+            assert!(hit.doc.text.is_char_boundary(position));
+            assert!(hit.doc.text[position..].to_lowercase().starts_with("spike"));
+        }
+
+        // Overlapping matches are each reported
+        assert_eq!(corpus.search("aa")[0].positions, vec![0, 1]);
+        assert!(corpus.search("  ").is_empty());
+        assert!(corpus.search("ORF8").is_empty());
+    }
+}
@@ -0,0 +1,955 @@
+// tests/graph_tests.rs
+#[cfg(test)]
+mod tests {
+    use limit_bio_sars::{
+        merge_provenance, BioGraph, BioNode, CitingEdge, DeprecatedNodes, ExpectedSchema, ExportOptions, HostReceptorNode,
+        NodeId, ProteinNode, ProvenanceEntry, RelationVocabulary, SimilarityMetric, TherapyNode, VariantNode, VirusNode,
+    };
+    use uuid::Uuid;
+
+    fn build_demo_graph() -> BioGraph {
+        let virus = VirusNode::new("SARS-CoV-2".to_string(), 29.9);
+        let mut graph = BioGraph::new(virus);
+
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+
+        graph.add_variant(VariantNode::new(
+            "Delta".to_string(),
+            vec!["L452R".to_string(), "T478K".to_string()],
+        ));
+        graph.add_variant(VariantNode::new(
+            "Omicron".to_string(),
+            vec!["N501Y".to_string(), "E484A".to_string()],
+        ));
+        graph.add_therapy(TherapyNode::new(
+            "mRNA Vaccine".to_string(),
+            "Induces neutralizing antibodies".to_string(),
+        ));
+
+        graph.link_with_confidence(
+            spike.id,
+            ace2.id,
+            "binds_to",
+            Some("High affinity binding".to_string()),
+            0.95,
+            vec!["PubMed:12345".to_string(), "Nature:2020".to_string()],
+        )
+        .unwrap();
+
+        graph
+    }
+
+    #[test]
+    fn test_node_link_json() {
+        let graph = build_demo_graph();
+        let json = graph.to_node_link_json();
+
+        assert_eq!(json["directed"], true);
+        assert!(json.get("multigraph").is_some());
+        assert_eq!(json["nodes"].as_array().unwrap().len(), graph.node_count());
+        assert_eq!(json["links"].as_array().unwrap().len(), graph.edge_count());
+
+        let link = &json["links"][0];
+        assert_eq!(link["relation"], "binds_to");
+        assert!(link.get("source").is_some());
+        assert!(link.get("target").is_some());
+        assert!((link["confidence"].as_f64().unwrap() - 0.95).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_relation_vocabulary() {
+        let virus = VirusNode::new("SARS-CoV-2".to_string(), 29.9);
+        let vocabulary = RelationVocabulary::new(["binds_to", "treats"]);
+        let mut graph = BioGraph::new(virus).with_vocabulary(vocabulary);
+
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let vaccine = TherapyNode::new("mRNA Vaccine".to_string(), "Antibodies".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_therapy(vaccine.clone());
+
+        let rejected = graph.link(spike.id, vaccine.id, "frobnicates", None);
+        assert!(rejected.is_err());
+        assert!(rejected.unwrap_err().to_string().contains("frobnicates"));
+
+        assert!(graph.link(vaccine.id, spike.id, "treats", None).is_ok());
+        assert_eq!(graph.edge_count(), 1);
+    }
+
+    #[test]
+    fn test_no_vocabulary_accepts_any_relation() {
+        let mut graph = build_demo_graph();
+        let spike = graph.find_protein("Spike Protein").unwrap().id;
+        let virus = graph.virus.id;
+
+        assert!(graph.link(spike, virus, "frobnicates", None).is_ok());
+    }
+
+    #[test]
+    fn test_edges_in_confidence_range() {
+        let mut graph = build_demo_graph();
+        let spike = graph.find_protein("Spike Protein").unwrap().id;
+        let vaccine = graph.find_therapy("mRNA Vaccine").unwrap().id;
+        let virus = graph.virus.id;
+
+        graph
+            .link_with_confidence(virus, spike, "encodes", None, 0.5, vec![])
+            .unwrap();
+        graph
+            .link_with_confidence(vaccine, spike, "targets", None, 0.8, vec![])
+            .unwrap();
+
+        let edges = graph.edges_in_confidence_range(0.7, 0.9).unwrap();
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].relation, "targets");
+    }
+
+    #[test]
+    fn test_completeness_report_flags_missing_protein() {
+        let graph = build_demo_graph();
+        let schema = ExpectedSchema {
+            min_variants: 3,
+            required_proteins: vec!["Spike Protein".to_string(), "Nucleocapsid".to_string()],
+            ..Default::default()
+        };
+
+        let report = graph.completeness_report(&schema, DeprecatedNodes::default());
+        assert!(!report.is_complete());
+        assert_eq!(report.missing_nodes.len(), 1);
+        assert_eq!(report.missing_nodes[0].category, "protein");
+        assert_eq!(report.missing_nodes[0].name, "Nucleocapsid");
+        assert_eq!(report.under_populated.len(), 1);
+        assert_eq!(report.under_populated[0].category, "variant");
+        assert_eq!(report.under_populated[0].actual, 2);
+    }
+
+    #[test]
+    fn test_node_evidence_score() {
+        let graph = build_demo_graph();
+        let spike = graph.find_protein("Spike Protein").unwrap().id;
+        let delta = graph.find_variant("Delta").unwrap().id;
+
+        assert!((graph.node_evidence_score(spike) - 1.9).abs() < 1e-6);
+        assert_eq!(graph.node_evidence_score(delta), 0.0);
+        assert!(graph.node_evidence_score(spike) > graph.node_evidence_score(delta));
+    }
+
+    #[test]
+    fn test_orphan_nodes() {
+        let mut graph = build_demo_graph();
+        let spike = graph.find_protein("Spike Protein").unwrap().id;
+        let antibody = TherapyNode::new(
+            "Sotrovimab".to_string(),
+            "Monoclonal antibody against the Spike Protein".to_string(),
+        );
+        graph.add_therapy(antibody.clone());
+
+        let orphans = graph.orphan_nodes();
+        assert!(orphans.contains(&antibody.id));
+        assert!(!orphans.contains(&spike));
+        assert!(!orphans.contains(&graph.virus.id));
+
+        let suggestions = graph.connect_orphans_suggestion();
+        let suggestion = suggestions
+            .iter()
+            .find(|s| s.src == antibody.id)
+            .expect("antibody linked by its mechanism");
+        assert_eq!(suggestion.dst, spike);
+        assert_eq!(suggestion.relation, "targets");
+    }
+
+    #[test]
+    fn test_explain_path() {
+        let graph = build_demo_graph();
+        let spike = graph.find_protein("Spike Protein").unwrap().id;
+        let ace2 = graph.receptors[0].id;
+
+        let hops = graph.explain_path(&[spike, ace2]).unwrap();
+        assert_eq!(hops.len(), 1);
+        assert_eq!(hops[0].from_name, "Spike Protein");
+        assert_eq!(hops[0].to_name, "ACE2");
+        assert_eq!(hops[0].relation, "binds_to");
+        assert_eq!(hops[0].confidence, 0.95);
+        assert_eq!(hops[0].provenance, vec!["PubMed:12345", "Nature:2020"]);
+        assert_eq!(
+            hops[0].to_string(),
+            "Spike Protein binds_to ACE2 (confidence 0.95; PubMed:12345, Nature:2020): High affinity binding"
+        );
+
+        // Reverse traversal reuses the edge; any unlinked hop fails the explanation
+        assert_eq!(graph.explain_path(&[ace2, spike]), Some(hops));
+        assert_eq!(graph.explain_path(&[spike, ace2, graph.virus.id]), None);
+        assert_eq!(graph.explain_path(&[spike, graph.virus.id, ace2]), None);
+        assert_eq!(graph.explain_path(&[spike]), Some(vec![]));
+    }
+
+    #[test]
+    fn test_local_density_finds_hotspot() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        let tmprss2 = HostReceptorNode::new("TMPRSS2".to_string());
+        let nrp1 = HostReceptorNode::new("NRP1".to_string());
+        let delta = VariantNode::new("Delta".to_string(), vec!["L452R".to_string()]);
+        let antibody = TherapyNode::new("mAb".to_string(), "Neutralizing antibody".to_string());
+        let vaccine = TherapyNode::new("mRNA Vaccine".to_string(), "Antibodies".to_string());
+        graph.add_protein(spike.clone());
+        for r in [&ace2, &tmprss2, &nrp1] {
+            graph.add_receptor(r.clone());
+        }
+        graph.add_variant(delta.clone());
+        graph.add_therapy(antibody.clone());
+        graph.add_therapy(vaccine.clone());
+
+        // Well-studied spike neighborhood: 5 of 6 possible edges
+        for (src, dst) in [
+            (spike.id, ace2.id),
+            (spike.id, tmprss2.id),
+            (spike.id, nrp1.id),
+            (ace2.id, tmprss2.id),
+            (ace2.id, nrp1.id),
+        ] {
+            graph.link(src, dst, "associated_with", None).unwrap();
+        }
+        // Sparse chain: delta - antibody - vaccine
+        graph.link(antibody.id, delta.id, "neutralizes", None).unwrap();
+        graph.link(vaccine.id, antibody.id, "associated_with", None).unwrap();
+
+        let all = DeprecatedNodes::default();
+        let ego = graph.ego_subgraph(spike.id, 1, all).unwrap();
+        assert_eq!(ego.nodes.len(), 4);
+        assert_eq!(ego.edges.len(), 5);
+        assert!((graph.local_density(spike.id, 1, all) - 5.0 / 6.0).abs() < 1e-6);
+        assert!((graph.local_density(delta.id, 2, all) - 2.0 / 3.0).abs() < 1e-6);
+        assert!(graph.local_density(spike.id, 2, all) > graph.local_density(delta.id, 2, all));
+
+        // Within one hop delta only reaches the antibody
+        assert_eq!(graph.ego_subgraph(delta.id, 1, all).unwrap().nodes, vec![delta.id, antibody.id]);
+        assert_eq!(graph.local_density(graph.virus.id, 3, all), 0.0);
+        assert!(graph.ego_subgraph(Uuid::new_v4(), 1, all).is_none());
+    }
+
+    #[test]
+    fn test_local_density_ignores_parallel_edges_and_self_loops() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        for relation in ["binds_to", "associated_with", "interacts_with"] {
+            graph.link(spike.id, ace2.id, relation, None).unwrap();
+        }
+        graph.link(ace2.id, spike.id, "associated_with", None).unwrap();
+        graph.link(spike.id, spike.id, "associated_with", None).unwrap();
+
+        // Five edges, but only one linked pair out of one possible
+        let ego = graph.ego_subgraph(spike.id, 1, DeprecatedNodes::default()).unwrap();
+        assert_eq!(ego.edges.len(), 5);
+        assert_eq!(graph.local_density(spike.id, 1, DeprecatedNodes::default()), 1.0);
+    }
+
+    #[test]
+    fn test_constrained_path() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        let tmprss2 = HostReceptorNode::new("TMPRSS2".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph.add_receptor(tmprss2.clone());
+        graph.link(spike.id, ace2.id, "binds_to", None).unwrap();
+        graph.link(ace2.id, tmprss2.id, "associated_with", None).unwrap();
+
+        let mechanistic = vec!["binds_to".to_string(), "cleaved_by".to_string()];
+        assert_eq!(graph.constrained_path(spike.id, tmprss2.id, &mechanistic), None);
+
+        graph.link(ace2.id, tmprss2.id, "cleaved_by", None).unwrap();
+        assert_eq!(
+            graph.constrained_path(spike.id, tmprss2.id, &mechanistic),
+            Some(vec![spike.id, ace2.id, tmprss2.id])
+        );
+    }
+
+    #[test]
+    fn test_shortest_path_over_undirected_chain() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        let tmprss2 = HostReceptorNode::new("TMPRSS2".to_string());
+        let vaccine = TherapyNode::new("mRNA Vaccine".to_string(), "Immunization".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph.add_receptor(tmprss2.clone());
+        graph.add_therapy(vaccine.clone());
+        graph.link(spike.id, ace2.id, "binds_to", None).unwrap();
+        // Points back up the chain; traversal ignores direction
+        graph.link(tmprss2.id, ace2.id, "associated_with", None).unwrap();
+
+        assert_eq!(
+            graph.shortest_path(spike.id, tmprss2.id, DeprecatedNodes::default()),
+            Some(vec![spike.id, ace2.id, tmprss2.id])
+        );
+        assert_eq!(
+            graph.shortest_path(tmprss2.id, spike.id, DeprecatedNodes::default()),
+            Some(vec![tmprss2.id, ace2.id, spike.id])
+        );
+        assert_eq!(graph.shortest_path(ace2.id, ace2.id, DeprecatedNodes::default()), Some(vec![ace2.id]));
+
+        // Endpoints that are not in the graph never yield a path
+        let unknown = Uuid::new_v4();
+        assert_eq!(graph.shortest_path(unknown, unknown, DeprecatedNodes::default()), None);
+        assert_eq!(graph.shortest_path(ace2.id, unknown, DeprecatedNodes::default()), None);
+
+        // The vaccine has no edges
+        assert_eq!(graph.shortest_path(spike.id, vaccine.id, DeprecatedNodes::default()), None);
+        assert_eq!(graph.shortest_path(vaccine.id, graph.virus.id, DeprecatedNodes::default()), None);
+    }
+
+    #[test]
+    fn test_traversals_skip_deprecated_nodes() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        let tmprss2 = HostReceptorNode::new("TMPRSS2".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph.add_receptor(tmprss2.clone());
+        graph.link_with_confidence(spike.id, ace2.id, "binds_to", None, 0.9, vec![]).unwrap();
+        graph.link_with_confidence(ace2.id, tmprss2.id, "associated_with", None, 0.9, vec![]).unwrap();
+        graph.deprecate_node(ace2.id, None).unwrap();
+
+        // ACE2 is the only bridge, so excluding it disconnects the chain
+        let (exclude, include) = (DeprecatedNodes::Exclude, DeprecatedNodes::Include);
+        assert_eq!(graph.shortest_path(spike.id, tmprss2.id, exclude), None);
+        assert_eq!(
+            graph.shortest_path(spike.id, tmprss2.id, include),
+            Some(vec![spike.id, ace2.id, tmprss2.id])
+        );
+        assert!(graph.min_cost_path(spike.id, tmprss2.id, exclude).is_none());
+        assert!(graph.best_confidence_path(spike.id, tmprss2.id, exclude).is_none());
+        assert!(graph.shortest_path(spike.id, ace2.id, exclude).is_none());
+
+        assert!(graph.neighbors(spike.id, None, exclude).is_empty());
+        assert_eq!(graph.ego_subgraph(spike.id, 2, exclude).unwrap().nodes, vec![spike.id]);
+        assert!(graph.ego_subgraph(ace2.id, 1, exclude).is_none());
+        assert_eq!(graph.local_density(spike.id, 2, exclude), 0.0);
+
+        let schema = ExpectedSchema {
+            required_receptors: vec!["ACE2".to_string()],
+            min_edges: 1,
+            ..Default::default()
+        };
+        let report = graph.completeness_report(&schema, exclude);
+        assert_eq!(report.missing_nodes[0].name, "ACE2");
+        assert_eq!(report.under_populated[0].category, "edge");
+        assert!(graph.completeness_report(&schema, include).is_complete());
+    }
+
+    #[test]
+    fn test_best_confidence_path_prefers_trusted_edges() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        let tmprss2 = HostReceptorNode::new("TMPRSS2".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph.add_receptor(tmprss2.clone());
+
+        // A single 0.95 edge (0.95) beats two 0.8 hops (0.64)
+        graph.link_with_confidence(spike.id, ace2.id, "associated_with", None, 0.8, vec![]).unwrap();
+        graph.link_with_confidence(ace2.id, tmprss2.id, "cleaved_by", None, 0.8, vec![]).unwrap();
+        graph.link_with_confidence(spike.id, tmprss2.id, "binds_to", None, 0.95, vec![]).unwrap();
+        let (path, confidence) = graph.best_confidence_path(spike.id, tmprss2.id, DeprecatedNodes::default()).unwrap();
+        assert_eq!(path, vec![spike.id, tmprss2.id]);
+        assert!((confidence - 0.95).abs() < 1e-6);
+
+        // ... but two 0.8 hops (0.64) beat a single 0.3 edge
+        graph.edges[2].confidence = 0.3;
+        let (path, confidence) = graph.best_confidence_path(tmprss2.id, spike.id, DeprecatedNodes::default()).unwrap();
+        assert_eq!(path, vec![tmprss2.id, ace2.id, spike.id]);
+        assert!((confidence - 0.64).abs() < 1e-5);
+
+        assert_eq!(graph.best_confidence_path(spike.id, spike.id, DeprecatedNodes::default()), Some((vec![spike.id], 1.0)));
+        assert_eq!(graph.best_confidence_path(spike.id, graph.virus.id, DeprecatedNodes::default()), None);
+    }
+
+    #[test]
+    fn test_min_cost_path() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        let tmprss2 = HostReceptorNode::new("TMPRSS2".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph.add_receptor(tmprss2.clone());
+
+        // Direct but weak (cost 0.8) vs. two strong hops (cost 0.1 + 0.2)
+        graph.link_with_confidence(spike.id, tmprss2.id, "associated_with", None, 0.2, vec![]).unwrap();
+        graph.link_with_confidence(spike.id, ace2.id, "binds_to", None, 0.9, vec![]).unwrap();
+        graph.link_with_confidence(ace2.id, tmprss2.id, "cleaved_by", None, 0.8, vec![]).unwrap();
+
+        let (path, cost) = graph.min_cost_path(spike.id, tmprss2.id, DeprecatedNodes::default()).unwrap();
+        assert_eq!(path, vec![spike.id, ace2.id, tmprss2.id]);
+        assert!((cost - 0.3).abs() < 1e-6);
+
+        assert_eq!(graph.min_cost_path(spike.id, spike.id, DeprecatedNodes::default()), Some((vec![spike.id], 0.0)));
+        assert_eq!(graph.min_cost_path(tmprss2.id, spike.id, DeprecatedNodes::default()), None);
+    }
+
+    #[test]
+    fn test_virus_try_new_validates_input() {
+        let err = VirusNode::try_new("SARS-CoV-2".to_string(), -29.9).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Virus 'SARS-CoV-2' genome size must be positive, got -29.9 kb"
+        );
+
+        assert!(VirusNode::try_new("  ".to_string(), 29.9).is_err());
+        assert!(VirusNode::try_new("SARS-CoV-2".to_string(), f32::NAN).is_err());
+        assert_eq!(VirusNode::try_new("SARS-CoV-2".to_string(), 29.9).unwrap().genome_kb, 29.9);
+    }
+
+    #[test]
+    fn test_variants_by_lineage_prefix() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        for (name, lineage) in [
+            ("Omicron BA.1", Some("BA.1")),
+            ("Omicron BA.2", Some("BA.2")),
+            ("Delta", Some("B.1.617.2")),
+            ("Unassigned", None),
+        ] {
+            let mut variant = VariantNode::new(name.to_string(), vec![]);
+            variant.lineage = lineage.map(str::to_string);
+            graph.add_variant(variant);
+        }
+
+        let names: Vec<&str> = graph
+            .variants_by_lineage_prefix("ba.")
+            .iter()
+            .map(|v| v.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Omicron BA.1", "Omicron BA.2"]);
+    }
+
+    #[test]
+    fn test_graphml_export() {
+        let graph = build_demo_graph();
+        let graphml = graph.to_graphml();
+
+        assert_eq!(graphml.matches("<node ").count(), graph.node_count());
+        assert_eq!(graphml.matches("<edge ").count(), graph.edge_count());
+        for key in ["label", "type", "relation", "weight"] {
+            assert!(graphml.contains(&format!("<key id=\"{}\"", key)));
+        }
+        assert!(graphml.contains(&format!(
+            "<node id=\"{}\"><data key=\"label\">SARS-CoV-2</data><data key=\"type\">virus</data></node>",
+            graph.virus.id
+        )));
+        assert!(graphml.contains("<data key=\"relation\">binds_to</data><data key=\"weight\">0.95</data>"));
+        assert!(graphml.trim_end().ends_with("</graphml>"));
+    }
+
+    #[test]
+    fn test_export_filtered_by_node_type() {
+        let graph = build_demo_graph();
+
+        let dot = graph.to_dot_with(&ExportOptions::new().with_node_types(&["variant"]));
+        assert!(dot.contains("label=\"Delta\""));
+        assert!(dot.contains("label=\"Omicron\""));
+        assert!(!dot.contains("type=\"therapy\""));
+        assert!(!dot.contains("mRNA Vaccine"));
+        assert!(!dot.contains("->"));
+
+        let graphml = graph.to_graphml_with(&ExportOptions::new().with_node_types(&["protein", "receptor"]));
+        assert_eq!(graphml.matches("<node ").count(), 2);
+        assert_eq!(graphml.matches("<edge ").count(), 1);
+
+        // The unfiltered exporters keep their original output
+        assert_eq!(graph.to_dot(), graph.to_dot_with(&ExportOptions::default()));
+        assert_eq!(graph.to_graphml(), graph.to_graphml_with(&ExportOptions::default()));
+    }
+
+    #[test]
+    fn test_export_with_provenance() {
+        let graph = build_demo_graph();
+        let options = ExportOptions::new().with_provenance();
+
+        let dot = graph.to_dot_with(&options);
+        assert!(dot.contains("provenance=\"PubMed:12345|Nature:2020\""));
+        assert!(!graph.to_dot().contains("provenance="));
+
+        let graphml = graph.to_graphml_with(&options);
+        assert!(graphml.contains("<key id=\"provenance\" for=\"edge\""));
+        assert!(graphml.contains("<data key=\"provenance\">PubMed:12345|Nature:2020</data>"));
+        assert!(!graph.to_graphml().contains("provenance"));
+    }
+
+    #[test]
+    fn test_provenance_index() {
+        let mut graph = build_demo_graph();
+        let spike = graph.find_protein("Spike Protein").unwrap().id;
+        let ace2 = graph.receptors[0].id;
+
+        let index = graph.provenance_index();
+        assert_eq!(index.len(), 2);
+        let binds = CitingEdge::Edge {
+            src: spike,
+            dst: ace2,
+            relation: "binds_to".to_string(),
+        };
+        assert_eq!(index["PubMed:12345"], vec![binds.clone()]);
+        assert_eq!(index["Nature:2020"], vec![binds.clone()]);
+
+        // A parallel edge is indexed on its own; a repeated source counts once
+        let cited_twice = vec!["PubMed:12345".to_string(), "PubMed:12345".to_string()];
+        graph
+            .link_with_confidence(spike, ace2, "interacts_with", None, 0.8, cited_twice)
+            .unwrap();
+        let index = graph.provenance_index();
+        let interacts = CitingEdge::Edge {
+            src: spike,
+            dst: ace2,
+            relation: "interacts_with".to_string(),
+        };
+        assert_eq!(index["PubMed:12345"], vec![binds.clone(), interacts.clone()]);
+        assert_eq!(
+            graph.retraction_impact("PubMed:12345").affected_edges.len(),
+            index["PubMed:12345"].len()
+        );
+
+        // Hyperedges are indexed after the edges
+        let omicron = graph.find_variant("Omicron").unwrap().id;
+        graph
+            .add_hyperedge(vec![spike, ace2, omicron], "binds_in_context", 0.7, vec!["PubMed:12345".to_string()])
+            .unwrap();
+        let binds_in_context = CitingEdge::HyperEdge {
+            nodes: vec![spike, ace2, omicron],
+            relation: "binds_in_context".to_string(),
+        };
+        assert_eq!(graph.provenance_index()["PubMed:12345"], vec![binds, interacts, binds_in_context]);
+    }
+
+    #[test]
+    fn test_deprecated_variant_excluded_from_ranking() {
+        let mut graph = build_demo_graph();
+        let mut renamed = VariantNode::new("B.1.1.529".to_string(), vec!["N501Y".to_string()]);
+        renamed.immune_escape = Some(0.9);
+        let mut omicron = VariantNode::new("Omicron BA.1".to_string(), vec!["N501Y".to_string()]);
+        omicron.immune_escape = Some(0.8);
+        let (renamed_id, omicron_id) = (renamed.id, omicron.id);
+        graph.add_variant(renamed);
+        graph.add_variant(omicron);
+
+        assert!(graph.deprecate_node(Uuid::new_v4(), None).is_err());
+        assert!(graph.deprecate_node(renamed_id, Some(renamed_id)).is_err());
+        graph.deprecate_node(renamed_id, Some(omicron_id)).unwrap();
+        assert!(graph.is_deprecated(renamed_id));
+        assert_eq!(graph.superseded_by(renamed_id), Some(omicron_id));
+        assert!(graph.find_variant("B.1.1.529").is_some());
+
+        let ranked = graph.rank_variants_by_immune_escape(DeprecatedNodes::default());
+        assert_eq!(ranked[0].id, omicron_id);
+        assert!(ranked.iter().all(|v| v.id != renamed_id));
+        let all = graph.rank_variants_by_immune_escape(DeprecatedNodes::Include);
+        assert_eq!(all[0].id, renamed_id);
+        assert!(!graph.to_markdown_report().contains("B.1.1.529"));
+    }
+
+    #[test]
+    fn test_merge_provenance_keeps_highest_confidence() {
+        let graph = build_demo_graph();
+        let existing = ProvenanceEntry::from_edge(&graph.edges[0]);
+        let incoming = vec![
+            ProvenanceEntry::new("Nature:2020", Some(0.99)),
+            ProvenanceEntry::new("PubMed:12345", Some(0.6)),
+            ProvenanceEntry::new("Lancet:2021", None),
+        ];
+
+        let merged = merge_provenance(&existing, &incoming);
+        assert_eq!(
+            merged,
+            vec![
+                ProvenanceEntry::new("PubMed:12345", Some(0.95)),
+                ProvenanceEntry::new("Nature:2020", Some(0.99)),
+                ProvenanceEntry::new("Lancet:2021", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_retraction_impact() {
+        let mut graph = build_demo_graph();
+        let spike = graph.find_protein("Spike Protein").unwrap().id;
+        let ace2 = graph.receptors[0].id;
+        let vaccine = graph.therapies[0].id;
+        graph
+            .link_with_confidence(vaccine, spike, "targets", None, 0.9, vec!["Lancet:2021".to_string()])
+            .unwrap();
+
+        let report = graph.retraction_impact("Lancet:2021");
+        assert_eq!(report.unsupported_edges, vec![(vaccine, spike)]);
+        assert_eq!(report.orphaned_nodes, vec![vaccine]);
+        assert_eq!(report.affected_edges[0].projected_confidence, 0.0);
+
+        // The spike/ACE2 edge keeps a second source but halves its confidence
+        let report = graph.retraction_impact("PubMed:12345");
+        assert!(report.unsupported_edges.is_empty());
+        assert_eq!(report.below_threshold, vec![(spike, ace2)]);
+        assert_eq!(report.affected_edges[0].remaining_sources, 1);
+        assert!((report.affected_edges[0].projected_confidence - 0.475).abs() < 1e-6);
+        assert!(report.orphaned_nodes.is_empty());
+
+        assert!(graph.retraction_impact("Unknown:0").affected_edges.is_empty());
+    }
+
+    #[test]
+    fn test_retraction_keeps_hyperedge_members_linked() {
+        let mut graph = build_demo_graph();
+        let spike = graph.find_protein("Spike Protein").unwrap().id;
+        let omicron = graph.find_variant("Omicron").unwrap().id;
+        let vaccine = graph.therapies[0].id;
+        graph
+            .link_with_confidence(vaccine, spike, "targets", None, 0.9, vec!["Lancet:2021".to_string()])
+            .unwrap();
+        graph
+            .add_hyperedge(vec![vaccine, omicron], "neutralizes_in_context", 0.7, vec!["PMID:35353979".to_string()])
+            .unwrap();
+
+        // The vaccine loses its only edge but is still a hyperedge member
+        let report = graph.retraction_impact("Lancet:2021");
+        assert_eq!(report.unsupported_edges, vec![(vaccine, spike)]);
+        assert!(report.orphaned_nodes.is_empty());
+        assert!(report.affected_hyperedges.is_empty());
+    }
+
+    #[test]
+    fn test_retraction_impact_on_hyperedges() {
+        let mut graph = build_demo_graph();
+        let spike = graph.find_protein("Spike Protein").unwrap().id;
+        let omicron = graph.find_variant("Omicron").unwrap().id;
+        let vaccine = graph.therapies[0].id;
+        let sources = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        graph
+            .add_hyperedge(vec![vaccine, omicron], "neutralizes_in_context", 0.7, sources(&["PMID:35353979"]))
+            .unwrap();
+        graph
+            .add_hyperedge(
+                vec![spike, omicron, vaccine],
+                "escapes_in_context",
+                0.8,
+                sources(&["PMID:35353979", "Cell:2022"]),
+            )
+            .unwrap();
+
+        // The first hyperedge loses its only source, the second halves below the threshold
+        let report = graph.retraction_impact("PMID:35353979");
+        assert!(report.affected_edges.is_empty());
+        assert_eq!(report.unsupported_hyperedges, vec![vec![vaccine, omicron]]);
+        assert_eq!(report.hyperedges_below_threshold, vec![vec![spike, omicron, vaccine]]);
+        assert_eq!(report.affected_hyperedges.len(), 2);
+        assert_eq!(report.affected_hyperedges[1].remaining_sources, 1);
+        assert!((report.affected_hyperedges[1].projected_confidence - 0.4).abs() < 1e-6);
+        // Every member is still in the surviving hyperedge
+        assert!(report.orphaned_nodes.is_empty());
+
+        // Once the second hyperedge is gone its members have nothing else
+        graph.hyperedges.pop();
+        let report = graph.retraction_impact("PMID:35353979");
+        let mut orphaned = report.orphaned_nodes;
+        orphaned.sort();
+        let mut expected = vec![vaccine, omicron];
+        expected.sort();
+        assert_eq!(orphaned, expected);
+    }
+
+    #[test]
+    fn test_cluster_variants() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let mutations = |list: &[&str]| list.iter().map(|m| m.to_string()).collect::<Vec<_>>();
+        let ba1 = VariantNode::new("BA.1".to_string(), mutations(&["N501Y", "E484A", "K417N", "T478K"]));
+        let ba2 = VariantNode::new("BA.2".to_string(), mutations(&["N501Y", "E484A", "K417N", "D405N"]));
+        let delta = VariantNode::new("Delta".to_string(), mutations(&["L452R", "T478K", "P681R"]));
+        for v in [&ba1, &ba2, &delta] {
+            graph.add_variant(v.clone());
+        }
+
+        let clusters = graph.cluster_variants(SimilarityMetric::Jaccard, 0.5);
+        assert_eq!(clusters, vec![vec![ba1.id, ba2.id], vec![delta.id]]);
+
+        // BA.1 shares one mutation with Delta, enough for a count threshold of 1
+        let clusters = graph.cluster_variants(SimilarityMetric::SharedCount, 1.0);
+        assert_eq!(clusters, vec![vec![ba1.id, ba2.id, delta.id]]);
+    }
+
+    #[test]
+    fn test_markdown_report() {
+        let report = build_demo_graph().to_markdown_report();
+
+        assert!(report.contains("- Total nodes: 6"));
+        let variants = report
+            .split("## Variants by Immune Escape")
+            .nth(1)
+            .and_then(|s| s.split("## ").next())
+            .unwrap();
+        assert!(variants.contains("Delta"));
+        assert!(variants.contains("Omicron"));
+        assert!(report.contains("- Spike Protein --binds_to--> ACE2 (0.95)"));
+        assert!(report.contains("- Edges with provenance: 1/1 (100%)"));
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut graph = build_demo_graph();
+        graph.metadata.insert("curator".to_string(), "lab".to_string());
+        let restored = BioGraph::from_json(&graph.to_json().unwrap()).unwrap();
+
+        assert_eq!(restored.virus.name, "SARS-CoV-2");
+        assert_eq!(restored.node_count(), graph.node_count());
+        assert_eq!(restored.edge_count(), graph.edge_count());
+        assert_eq!(restored.metadata["curator"], "lab");
+        assert_eq!(restored.to_canonical_json().unwrap(), graph.to_canonical_json().unwrap());
+        assert!(!restored.is_indexed());
+
+        // The pretty form is multi-line and restores the same graph
+        let pretty = graph.to_json_pretty().unwrap();
+        assert!(!graph.to_json().unwrap().contains('\n'));
+        assert!(pretty.contains('\n'));
+        let from_pretty = BioGraph::from_json(&pretty).unwrap();
+        assert_eq!(from_pretty.to_canonical_json().unwrap(), graph.to_canonical_json().unwrap());
+
+        assert!(BioGraph::from_json("{\"virus\": {}}").is_err());
+    }
+
+    #[test]
+    fn test_hyperedge_members_are_mutual_neighbors() {
+        let mut graph = build_demo_graph();
+        let therapy = TherapyNode::new("Sotrovimab".to_string(), "Monoclonal antibody".to_string());
+        graph.add_therapy(therapy.clone());
+        let spike = graph.find_protein("Spike Protein").unwrap().id;
+        let omicron = graph.find_variant("Omicron").unwrap().id;
+
+        graph
+            .add_hyperedge(
+                vec![therapy.id, spike, omicron],
+                "targets_in_context",
+                0.8,
+                vec!["PMID:35353979".to_string()],
+            )
+            .unwrap();
+        assert!(graph.add_hyperedge(vec![spike, spike], "targets_in_context", 0.8, vec![]).is_err());
+
+        let members = [therapy.id, spike, omicron];
+        for member in members {
+            let neighbors = graph.hyperedge_neighbors(member);
+            assert_eq!(neighbors.len(), 2);
+            assert!(members.iter().filter(|m| **m != member).all(|m| neighbors.contains(m)));
+        }
+        assert_eq!(graph.hyperedges[0].pairs().len(), 3);
+        assert!(!graph.orphan_nodes().contains(&therapy.id));
+    }
+
+    #[test]
+    fn test_neighbors_by_relation_and_direction() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        let vaccine = TherapyNode::new("mRNA Vaccine".to_string(), "Immunization".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph.add_therapy(vaccine.clone());
+        graph.link(spike.id, ace2.id, "binds_to", None).unwrap();
+        graph.link(vaccine.id, spike.id, "targets", None).unwrap();
+        graph.link(spike.id, spike.id, "interacts_with", None).unwrap();
+
+        let all: Vec<Uuid> = graph
+            .neighbors(spike.id, None, DeprecatedNodes::default())
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(all, vec![ace2.id, vaccine.id, spike.id]);
+
+        let binds = graph.neighbors(spike.id, Some("binds_to"), DeprecatedNodes::default());
+        assert_eq!(binds.len(), 1);
+        assert_eq!(binds[0].0, ace2.id);
+        assert_eq!(binds[0].1.relation, "binds_to");
+
+        // Seen from the destination, the neighbor is the source
+        let from_ace2 = graph.neighbors(ace2.id, Some("binds_to"), DeprecatedNodes::default());
+        assert_eq!(from_ace2[0].0, spike.id);
+        assert!(graph.neighbors(ace2.id, Some("targets"), DeprecatedNodes::default()).is_empty());
+    }
+
+    #[test]
+    fn test_get_node_by_id_resolves_edge_endpoints() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph.link(spike.id, ace2.id, "binds_to", None).unwrap();
+
+        let edge = &graph.edges[0];
+        let src = graph.get_node_by_id(edge.src).unwrap();
+        let dst = graph.get_node_by_id(edge.dst).unwrap();
+        assert!(matches!(src, BioNode::Protein(ref p) if p.name == "Spike Protein"));
+        assert!(matches!(dst, BioNode::Receptor(ref r) if r.name == "ACE2"));
+        assert_eq!((src.id(), dst.name(), dst.node_type()), (spike.id, "ACE2", "receptor"));
+
+        let virus = graph.get_node_by_id(graph.virus.id).unwrap();
+        assert_eq!((virus.name(), virus.node_type()), ("SARS-CoV-2", "virus"));
+        assert!(graph.get_node_by_id(Uuid::new_v4()).is_none());
+    }
+
+    #[test]
+    fn test_canonical_json_ignores_insertion_order() {
+        let virus = VirusNode::new("SARS-CoV-2".to_string(), 29.9);
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let nsp1 = ProteinNode::new("NSP1".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+
+        let mut forward = BioGraph::new(virus.clone());
+        forward.metadata.insert("source".to_string(), "demo".to_string());
+        forward.metadata.insert("curator".to_string(), "lab".to_string());
+        forward.add_protein(spike.clone());
+        forward.add_protein(nsp1.clone());
+        forward.add_receptor(ace2.clone());
+        forward.link(spike.id, ace2.id, "binds_to", None).unwrap();
+        forward.link(nsp1.id, spike.id, "associated_with", None).unwrap();
+
+        let mut reverse = BioGraph::new(virus);
+        reverse.id = forward.id;
+        reverse.metadata.insert("curator".to_string(), "lab".to_string());
+        reverse.metadata.insert("source".to_string(), "demo".to_string());
+        reverse.add_receptor(ace2.clone());
+        reverse.add_protein(nsp1.clone());
+        reverse.add_protein(spike.clone());
+        reverse.link(nsp1.id, spike.id, "associated_with", None).unwrap();
+        reverse.link(spike.id, ace2.id, "binds_to", None).unwrap();
+
+        let canonical = forward.to_canonical_json().unwrap();
+        assert_eq!(canonical, reverse.to_canonical_json().unwrap());
+        assert_eq!(canonical, forward.to_canonical_json().unwrap());
+
+        let parsed: BioGraph = serde_json::from_str(&canonical).unwrap();
+        assert_eq!(parsed.node_count(), forward.node_count());
+        assert_eq!(parsed.edge_count(), forward.edge_count());
+    }
+
+    #[test]
+    fn test_remove_protein_drops_incident_edges() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let nsp1 = ProteinNode::new("NSP1".to_string());
+        let orf8 = ProteinNode::new("ORF8".to_string());
+        for p in [&spike, &nsp1, &orf8] {
+            graph.add_protein(p.clone());
+        }
+        graph.index();
+        graph.link(spike.id, nsp1.id, "associated_with", None).unwrap();
+        graph.link(nsp1.id, orf8.id, "associated_with", None).unwrap();
+        assert_eq!((graph.node_count(), graph.edge_count()), (4, 2));
+
+        let removed = graph.remove_protein(spike.id).unwrap();
+        assert_eq!(removed.id, spike.id);
+        assert_eq!((graph.node_count(), graph.edge_count()), (3, 1));
+        assert!(graph.find_protein("Spike Protein").is_none());
+        assert!(graph.edges_for_node(spike.id).is_empty());
+        assert_eq!((graph.edges[0].src, graph.edges[0].dst), (nsp1.id, orf8.id));
+        // The index follows the shifted positions
+        assert_eq!(graph.find_protein("ORF8").map(|p| p.id), Some(orf8.id));
+
+        assert!(graph.remove_protein(spike.id).is_none());
+        assert!(graph.remove_variant(orf8.id).is_none());
+    }
+
+    #[test]
+    fn test_remove_node_shrinks_hyperedges() {
+        let mut graph = build_demo_graph();
+        let spike = graph.find_protein("Spike Protein").unwrap().id;
+        let ace2 = graph.receptors[0].id;
+        let omicron = graph.find_variant("Omicron").unwrap().id;
+        let vaccine = graph.therapies[0].id;
+        graph
+            .add_hyperedge(vec![spike, ace2, omicron], "binds_in_context", 0.7, vec![])
+            .unwrap();
+        graph
+            .add_hyperedge(vec![vaccine, omicron], "neutralizes_in_context", 0.6, vec![])
+            .unwrap();
+
+        // The three-member hyperedge keeps the facts between its other members
+        graph.remove_variant(omicron).unwrap();
+        assert_eq!(graph.hyperedges.len(), 1);
+        assert_eq!(graph.hyperedges[0].relation, "binds_in_context");
+        assert_eq!(graph.hyperedges[0].nodes, vec![spike, ace2]);
+
+        // Down to one member, it no longer relates anything
+        graph.remove_protein(spike).unwrap();
+        assert!(graph.hyperedges.is_empty());
+    }
+
+    #[test]
+    fn test_indexed_lookups_match_linear_scan() {
+        let mut linear = build_demo_graph();
+        for i in 0..500 {
+            linear.add_protein(ProteinNode::new(format!("Protein {}", i)));
+            let mut variant = VariantNode::new(format!("Variant {}", i), vec![format!("M{}", i)]);
+            variant.immune_escape = Some((i % 100) as f32 / 100.0);
+            linear.add_variant(variant);
+        }
+        let mut indexed = linear.clone();
+        indexed.index();
+        assert!(indexed.is_indexed() && !linear.is_indexed());
+
+        // Nodes added after indexing are picked up too
+        let orf8 = ProteinNode::new("ORF8".to_string());
+        linear.add_protein(orf8.clone());
+        indexed.add_protein(orf8);
+
+        for name in ["Spike Protein", "Protein 0", "Protein 499", "ORF8", "Missing"] {
+            assert_eq!(
+                indexed.find_protein(name).map(|p| p.id),
+                linear.find_protein(name).map(|p| p.id)
+            );
+        }
+        assert_eq!(indexed.find_variant("Variant 42").map(|v| v.id), linear.find_variant("Variant 42").map(|v| v.id));
+        assert_eq!(indexed.find_by_name("ORF8"), linear.find_by_name("ORF8"));
+
+        let in_range = indexed.nodes_in_range("immune_escape", 0.9, 0.95);
+        assert_eq!(in_range.len(), 30);
+        assert_eq!(in_range, linear.nodes_in_range("immune_escape", 0.9, 0.95));
+        assert!(indexed.nodes_in_range("unknown", 0.0, 1.0).is_empty());
+
+        // Editing the public node vectors directly leaves the index stale
+        indexed.proteins.insert(0, ProteinNode::new("NSP1".to_string()));
+        assert_eq!(indexed.find_protein("ORF8").map(|p| p.id), linear.find_protein("ORF8").map(|p| p.id));
+        assert_eq!(indexed.find_protein("NSP1").map(|p| p.name.as_str()), Some("NSP1"));
+        indexed.proteins.truncate(1);
+        indexed.variants.clear();
+        assert!(indexed.find_protein("ORF8").is_none());
+        assert!(indexed.find_variant("Variant 42").is_none());
+    }
+
+    #[test]
+    fn test_node_id_lookup_and_serde() {
+        let graph = build_demo_graph();
+        let spike = NodeId::from(graph.find_protein("Spike Protein").unwrap().id);
+
+        assert_eq!(graph.get_node(spike), Some(("Spike Protein", "protein")));
+        assert_eq!(graph.get_node(NodeId::from(uuid::Uuid::new_v4())), None);
+
+        // Serializes as the bare UUID, so existing payloads stay compatible
+        let json = serde_json::to_string(&spike).unwrap();
+        assert_eq!(json, format!("\"{}\"", spike));
+        assert_eq!(serde_json::from_str::<NodeId>(&json).unwrap(), spike);
+    }
+
+    #[test]
+    fn test_edges_in_confidence_range_rejects_inverted_bounds() {
+        let graph = build_demo_graph();
+        assert!(graph.edges_in_confidence_range(0.9, 0.7).is_err());
+        assert!(graph.edges_in_confidence_range(f32::NAN, 0.7).is_err());
+        assert_eq!(graph.edges_in_confidence_range(0.95, 0.95).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,104 @@
+// tests/loader_tests.rs
+#[cfg(test)]
+mod tests {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use limit_bio_sars::{BioGraphLoader, ConfidencePolicy, ProteinNode, VariantNode};
+    use std::fs;
+    use std::io::Write;
+
+    fn create_test_proteins() -> Vec<ProteinNode> {
+        vec![
+            ProteinNode::new("Spike Protein".to_string()),
+            ProteinNode::new("Nucleocapsid".to_string()),
+        ]
+    }
+
+    #[test]
+    fn test_load_gzipped_proteins() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("proteins.json.gz");
+        let proteins = create_test_proteins();
+
+        let mut encoder = GzEncoder::new(fs::File::create(&path).unwrap(), Compression::default());
+        encoder
+            .write_all(serde_json::to_string(&proteins).unwrap().as_bytes())
+            .unwrap();
+        encoder.finish().unwrap();
+
+        let mut loader = BioGraphLoader::new();
+        let loaded = loader.load_protein_nodes(&path).unwrap();
+
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0].name, "Spike Protein");
+        assert_eq!(loaded[1].id, proteins[1].id);
+        assert_eq!(loader.stats().nodes_loaded, 2);
+    }
+
+    #[test]
+    fn test_load_plain_proteins() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("proteins.json");
+        fs::write(&path, serde_json::to_string(&create_test_proteins()).unwrap()).unwrap();
+
+        let mut loader = BioGraphLoader::new();
+        let loaded = loader.load_protein_nodes(&path).unwrap();
+
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[1].name, "Nucleocapsid");
+    }
+
+    #[test]
+    fn test_variant_without_mutations_warns() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("variants.json");
+        let variants = vec![
+            VariantNode::new("Delta".to_string(), vec!["L452R".to_string()]),
+            VariantNode::new("Unknown".to_string(), vec![]),
+        ];
+        fs::write(&path, serde_json::to_string(&variants).unwrap()).unwrap();
+
+        let mut loader = BioGraphLoader::new();
+        let loaded = loader.load_variant_nodes(&path).unwrap();
+
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loader.warnings().len(), 1);
+        assert_eq!(loader.warnings()[0].node_id, variants[1].id);
+        assert!(loader.warnings()[0].message.contains("no mutations"));
+        assert_eq!(loader.stats().warnings, 1);
+    }
+
+    #[test]
+    fn test_out_of_range_edge_confidence() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("edges.json");
+        let edge = |confidence: f64| {
+            serde_json::json!({
+                "src": uuid::Uuid::new_v4(),
+                "dst": uuid::Uuid::new_v4(),
+                "relation": "binds_to",
+                "evidence": null,
+                "confidence": confidence,
+                "provenance": ["PubMed:12345"],
+                "metadata": {},
+            })
+        };
+        fs::write(&path, serde_json::json!([edge(0.9), edge(1.4)]).to_string()).unwrap();
+
+        let mut strict = BioGraphLoader::new().with_confidence_policy(ConfidencePolicy::Strict);
+        let error = strict.load_edges(&path).unwrap_err().to_string();
+        assert!(error.contains("Edge 1"));
+        assert!(error.contains("confidence 1.4 outside [0, 1]"));
+        assert_eq!(strict.stats().edges_loaded, 0);
+
+        let mut clamping = BioGraphLoader::new().with_confidence_policy(ConfidencePolicy::Clamp);
+        let edges = clamping.load_edges(&path).unwrap();
+        assert_eq!(edges[0].confidence, 0.9);
+        assert_eq!(edges[1].confidence, 1.0);
+        assert_eq!(clamping.edge_warnings.len(), 1);
+
+        // The default loader keeps confidences as given
+        let edges = BioGraphLoader::new().load_edges(&path).unwrap();
+        assert_eq!(edges[1].confidence, 1.4);
+    }
+}
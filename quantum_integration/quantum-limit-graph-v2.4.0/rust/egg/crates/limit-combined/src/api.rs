@@ -1,4 +1,4 @@
-// crates/limit-hub/src/api2.rs
+// crates/limit-combined/src/api.rs
 // Combined API with Hub + Reflection integration
 
 use axum::{
@@ -11,14 +11,25 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use tower_http::limit::RequestBodyLimitLayer;
 
-use crate::governance::{GovernanceRules, Submission, ValidationResult};
 use crate::state::CombinedHubState;
-use limit_reflection::{MetaCognitiveInsights, ReflectionResult};
+use limit_bio_sars::BioGraph;
+use limit_hub::{
+    rank_evidence, EvidenceAggregation, EvidenceWeights, Submission, SubmissionId,
+    ValidationResult,
+};
+use limit_reflection::{error_status, sanitize_query, MetaCognitiveInsights, ReflectionError};
 
 /// Create combined Hub + Reflection API router
 pub fn create_combined_router() -> Router {
-    let state = Arc::new(RwLock::new(CombinedHubState::new()));
+    create_combined_router_with_state(CombinedHubState::new())
+}
+
+/// Create combined router around an existing hub state
+pub fn create_combined_router_with_state(state: CombinedHubState) -> Router {
+    let body_limit = RequestBodyLimitLayer::new(state.max_body_bytes);
+    let state = Arc::new(RwLock::new(state));
 
     Router::new()
         // Hub endpoints
@@ -32,8 +43,11 @@ pub fn create_combined_router() -> Router {
         .route("/deep-reflect", post(deep_reflect))
         .route("/insights", get(get_insights))
         .route("/suggestions", get(get_suggestions))
+        // Graph endpoints
+        .route("/graph/import", post(import_graph))
         // Combined endpoints
         .route("/reflect-with-evidence", post(reflect_with_evidence))
+        .layer(body_limit)
         .with_state(state)
 }
 
@@ -51,7 +65,7 @@ async fn health_check() -> Json<HealthResponse> {
 
 async fn submit_data(
     State(state): State<Arc<RwLock<CombinedHubState>>>,
-    Json(submission): Json<Submission>,
+    Json(mut submission): Json<Submission>,
 ) -> Result<Json<SubmitResponse>, StatusCode> {
     let mut state = state.write().await;
     let validation = state.governance.validate_submission(&submission);
@@ -60,6 +74,12 @@ async fn submit_data(
         return Err(StatusCode::BAD_REQUEST);
     }
 
+    if state.submissions.iter().any(|s| s.id == submission.id) {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    submission.version = 1;
+    submission.created_at = Some(chrono::Utc::now().to_rfc3339());
     state.submissions.push(submission.clone());
 
     Ok(Json(SubmitResponse {
@@ -88,13 +108,13 @@ async fn list_submissions(
 
 async fn get_submission(
     State(state): State<Arc<RwLock<CombinedHubState>>>,
-    Path(id): Path<String>,
+    Path(id): Path<SubmissionId>,
 ) -> Result<Json<Submission>, StatusCode> {
     let state = state.read().await;
     state
         .submissions
         .iter()
-        .find(|s| s.id == id)
+        .find(|s| s.id == id.as_str())
         .cloned()
         .map(Json)
         .ok_or(StatusCode::NOT_FOUND)
@@ -120,7 +140,7 @@ async fn reflect_on_query(
 
     let result = state
         .reflect_with_context(&request.query)
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(|e| error_status(&e))?;
 
     Ok(Json(ReflectResponse {
         steps_count: result.steps.len(),
@@ -138,7 +158,7 @@ async fn deep_reflect(
     let result = state
         .reflection_engine
         .deep_reflect(&request.query)
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(|e| error_status(&e))?;
 
     Ok(Json(DeepReflectResponse {
         layers_count: result.layers.len(),
@@ -156,14 +176,37 @@ async fn get_insights(
 
 async fn get_suggestions(
     State(state): State<Arc<RwLock<CombinedHubState>>>,
-) -> Json<SuggestionsResponse> {
+) -> Result<Json<SuggestionsResponse>, StatusCode> {
     let state = state.read().await;
-    let suggestions = state.reflection_engine.get_suggestions();
+    let suggestions = state
+        .reflection_engine
+        .get_suggestions()
+        .map_err(|e| error_status(&e))?;
 
-    Json(SuggestionsResponse {
+    Ok(Json(SuggestionsResponse {
         total: suggestions.len(),
         suggestions,
-    })
+    }))
+}
+
+// ============================================================================
+// Graph Endpoints
+// ============================================================================
+
+async fn import_graph(
+    State(state): State<Arc<RwLock<CombinedHubState>>>,
+    Json(graph): Json<BioGraph>,
+) -> Json<GraphImportResponse> {
+    let mut state = state.write().await;
+
+    let response = GraphImportResponse {
+        graph_id: graph.id.to_string(),
+        nodes: graph.node_count(),
+        edges: graph.edge_count(),
+    };
+    state.bio_graph = Some(graph);
+
+    Json(response)
 }
 
 // ============================================================================
@@ -174,33 +217,51 @@ async fn reflect_with_evidence(
     State(state): State<Arc<RwLock<CombinedHubState>>>,
     Json(request): Json<ReflectWithEvidenceRequest>,
 ) -> Result<Json<ReflectWithEvidenceResponse>, StatusCode> {
+    if !(0.0..=1.0).contains(&request.reflection_weight) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    // The evidence marker would otherwise make a blank query look non-empty
+    if request.query.trim().is_empty() {
+        return Err(error_status(&ReflectionError::EmptyQuery));
+    }
+
     let state_guard = state.read().await;
 
-    // Get relevant submissions as evidence
-    let evidence: Vec<EvidenceItem> = state_guard
-        .submissions
-        .iter()
-        .filter(|s| s.confidence >= 0.7)
-        .take(5)
-        .map(|s| EvidenceItem {
-            id: s.id.clone(),
-            content: s.content.clone(),
-            confidence: s.confidence,
-            provenance: s.provenance.clone(),
-        })
-        .collect();
+    // Get relevant submissions as evidence, strongest and most recent first
+    let evidence: Vec<EvidenceItem> = rank_evidence(
+        &state_guard.submissions,
+        &request.weights,
+        chrono::Utc::now(),
+    )
+    .into_iter()
+    .filter(|s| s.confidence >= 0.7)
+    .take(5)
+    .map(|s| EvidenceItem {
+        id: s.id.clone(),
+        content: s.content.clone(),
+        confidence: s.confidence,
+        provenance: s.provenance.clone(),
+    })
+    .collect();
 
-    // Perform reflection with evidence context
+    // Perform reflection with evidence context; user text is sanitized so it
+    // cannot spoof the marker
     let enriched_query = format!(
         "{} [Evidence items: {}]",
-        request.query,
+        sanitize_query(&request.query),
         evidence.len()
     );
 
     let reflection = state_guard
         .reflection_engine
         .reflect_on_query(&enriched_query)
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(|e| error_status(&e))?;
+
+    let evidence_confidence = request
+        .aggregation
+        .aggregate(evidence.iter().map(|e| e.confidence));
+    let combined_confidence = request.reflection_weight * reflection.final_confidence
+        + (1.0 - request.reflection_weight) * evidence_confidence;
 
     Ok(Json(ReflectWithEvidenceResponse {
         query: request.query,
@@ -210,9 +271,7 @@ async fn reflect_with_evidence(
             final_confidence: reflection.final_confidence,
             insights: reflection.insights,
         },
-        combined_confidence: (reflection.final_confidence
-            + evidence.iter().map(|e| e.confidence).sum::<f32>() / evidence.len() as f32)
-            / 2.0,
+        combined_confidence,
     }))
 }
 
@@ -266,9 +325,27 @@ struct SuggestionsResponse {
     suggestions: Vec<limit_reflection::Suggestion>,
 }
 
+#[derive(Debug, Serialize)]
+struct GraphImportResponse {
+    graph_id: String,
+    nodes: usize,
+    edges: usize,
+}
+
 #[derive(Debug, Deserialize)]
 struct ReflectWithEvidenceRequest {
     query: String,
+    #[serde(default)]
+    weights: EvidenceWeights,
+    /// Share of the combined confidence taken from the reflection, in `[0, 1]`
+    #[serde(default = "default_reflection_weight")]
+    reflection_weight: f32,
+    #[serde(default)]
+    aggregation: EvidenceAggregation,
+}
+
+fn default_reflection_weight() -> f32 {
+    0.5
 }
 
 #[derive(Debug, Serialize)]
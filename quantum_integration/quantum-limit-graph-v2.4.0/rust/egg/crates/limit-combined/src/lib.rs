@@ -0,0 +1,6 @@
+// crates/limit-combined/src/lib.rs
+pub mod api;
+pub mod state;
+
+pub use api::{create_combined_router, create_combined_router_with_state};
+pub use state::{initialize_graph, CombinedHubState};
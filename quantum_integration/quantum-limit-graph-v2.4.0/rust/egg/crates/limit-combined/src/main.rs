@@ -0,0 +1,35 @@
+// crates/limit-combined/src/main.rs
+// Combined Hub + Reflection server
+
+use limit_combined::{create_combined_router_with_state, initialize_graph, CombinedHubState};
+
+#[tokio::main]
+async fn main() {
+    // Initialize tracing
+    tracing_subscriber::fmt::init();
+
+    tracing::info!("Initializing LIMIT Hub with Reflection...");
+
+    // Initialize biomedical graph
+    let bio_graph = initialize_graph();
+
+    // Create combined router
+    let state = CombinedHubState::new().with_graph(bio_graph);
+    let app = create_combined_router_with_state(state);
+
+    // Start server on port 3002
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:3002")
+        .await
+        .unwrap();
+
+    tracing::info!(
+        "LIMIT Hub + Reflection API listening on {}",
+        listener.local_addr().unwrap()
+    );
+    tracing::info!("Endpoints:");
+    tracing::info!("  Hub: /submit, /submissions, /validate");
+    tracing::info!("  Reflection: /reflect, /deep-reflect, /insights");
+    tracing::info!("  Combined: /reflect-with-evidence");
+
+    axum::serve(listener, app).await.unwrap();
+}
@@ -0,0 +1,80 @@
+// crates/limit-combined/src/state.rs
+use limit_bio_sars::{BioGraph, HostReceptorNode, ProteinNode, VirusNode};
+use limit_hub::{GovernanceRules, Submission, DEFAULT_MAX_BODY_BYTES};
+use limit_reflection::{ReflectionEngine, ReflectionError};
+
+/// Combined hub state with reflection and graph
+pub struct CombinedHubState {
+    pub governance: GovernanceRules,
+    pub submissions: Vec<Submission>,
+    pub reflection_engine: ReflectionEngine,
+    pub bio_graph: Option<BioGraph>,
+    /// Request body size limit applied by the router
+    pub max_body_bytes: usize,
+}
+
+impl CombinedHubState {
+    pub fn new() -> Self {
+        Self {
+            governance: GovernanceRules::default_rules(),
+            submissions: vec![],
+            reflection_engine: ReflectionEngine::new(3),
+            bio_graph: None,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+        }
+    }
+
+    /// Initialize with biomedical graph
+    pub fn with_graph(mut self, graph: BioGraph) -> Self {
+        self.bio_graph = Some(graph);
+        self
+    }
+
+    /// Get reflection insights
+    pub fn get_reflection_insights(&self) -> limit_reflection::MetaCognitiveInsights {
+        self.reflection_engine.get_insights()
+    }
+
+    /// Reflect on a query, grounded in graph evidence when a graph is loaded
+    pub fn reflect_with_context(
+        &self,
+        query: &str,
+    ) -> Result<limit_reflection::ReflectionResult, ReflectionError> {
+        match self.bio_graph {
+            Some(ref graph) => self.reflection_engine.reflect_with_graph(query, graph),
+            None => self.reflection_engine.reflect_on_query(query),
+        }
+    }
+}
+
+impl Default for CombinedHubState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Initialize biomedical graph with sample data
+pub fn initialize_graph() -> BioGraph {
+    tracing::info!("Initializing biomedical graph...");
+
+    let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+
+    // Add sample SARS-CoV-2 nodes
+    let mut spike = ProteinNode::new("Spike Protein".to_string());
+    spike.role = Some("SARS-CoV-2 spike glycoprotein".to_string());
+    let mut ace2 = HostReceptorNode::new("ACE2".to_string());
+    ace2.metadata.insert(
+        "description".to_string(),
+        "Angiotensin-converting enzyme 2".to_string(),
+    );
+    graph.add_protein(spike.clone());
+    graph.add_receptor(ace2.clone());
+
+    // Add relationship (no vocabulary is set, so linking cannot fail)
+    graph
+        .link(spike.id, ace2.id, "binds_to", None)
+        .expect("unrestricted relations");
+
+    tracing::info!("Graph initialized with {} nodes", graph.node_count());
+    graph
+}
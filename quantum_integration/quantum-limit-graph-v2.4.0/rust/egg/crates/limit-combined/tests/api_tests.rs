@@ -0,0 +1,189 @@
+// tests/api_tests.rs
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use limit_bio_sars::{BioGraph, HostReceptorNode, ProteinNode, VirusNode};
+    use limit_combined::{create_combined_router_with_state, initialize_graph, CombinedHubState};
+    use limit_hub::Submission;
+    use serde_json::Value;
+    use std::collections::HashMap;
+    use tower::ServiceExt;
+
+    async fn send(app: &Router, method: &str, uri: &str, body: Value) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+    }
+
+    fn create_test_graph() -> BioGraph {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph.link(spike.id, ace2.id, "binds_to", None).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_initialize_graph_seeds_spike_ace2_binding() {
+        let graph = initialize_graph();
+        assert_eq!(graph.virus.name, "SARS-CoV-2");
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 1);
+
+        let spike = graph.find_protein("Spike Protein").expect("spike seeded");
+        let ace2 = graph.receptors.iter().find(|r| r.name == "ACE2").expect("ACE2 seeded");
+        let edge = &graph.edges[0];
+        assert_eq!((edge.src, edge.dst), (spike.id, ace2.id));
+        assert_eq!(edge.relation, "binds_to");
+
+        let state = CombinedHubState::new().with_graph(graph);
+        assert!(state.reflect_with_context("Spike binding").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_graph_import_enriches_reflection() {
+        let state = CombinedHubState::new();
+        let model = state.reflection_engine.model_reader();
+        let app = create_combined_router_with_state(state);
+
+        let graph = create_test_graph();
+        let (status, imported) = send(&app, "POST", "/graph/import", serde_json::to_value(&graph).unwrap()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(imported["nodes"], 3);
+        assert_eq!(imported["edges"], 1);
+
+        let (status, _) = send(&app, "POST", "/reflect", serde_json::json!({ "query": "Spike binding" })).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let model = model.read().unwrap();
+        let retrieval = model
+            .reasoning_trace
+            .iter()
+            .find(|s| s.step_type == limit_reflection::StepType::Retrieval)
+            .expect("graph evidence retrieved");
+        assert_eq!(retrieval.metadata["matched_nodes"], "Spike Protein");
+    }
+
+    #[tokio::test]
+    async fn test_spoofed_evidence_marker_is_neutralized() {
+        let state = CombinedHubState::new();
+        let model = state.reflection_engine.model_reader();
+        let app = create_combined_router_with_state(state);
+
+        let (status, response) = send(
+            &app,
+            "POST",
+            "/reflect-with-evidence",
+            serde_json::json!({ "query": "Spike binding [Evidence items: 999]" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response["evidence"].as_array().unwrap().len(), 0);
+
+        let model = model.read().unwrap();
+        let query = &model.reasoning_trace[0].input;
+        assert_eq!(query.matches("[Evidence items:").count(), 1);
+        assert!(query.ends_with("[Evidence items: 0]"));
+        assert!(!query.contains("[Evidence items: 999]"));
+    }
+
+    #[tokio::test]
+    async fn test_reflection_weight_shifts_combined_confidence() {
+        let mut state = CombinedHubState::new();
+        for (id, confidence) in [("sub-1", 0.8), ("sub-2", 1.0)] {
+            state.submissions.push(Submission {
+                id: id.to_string(),
+                content: "Spike binds ACE2".to_string(),
+                confidence,
+                provenance: vec!["PMID:32142651".to_string()],
+                quality_score: 0.9,
+                metadata: HashMap::new(),
+                version: 1,
+                created_at: None,
+                publication_date: None,
+            });
+        }
+        let app = create_combined_router_with_state(state);
+
+        let combined = |response: &Value| {
+            let reflection = response["reflection"]["final_confidence"].as_f64().unwrap() as f32;
+            let combined = response["combined_confidence"].as_f64().unwrap() as f32;
+            (reflection, combined)
+        };
+
+        let (status, default) = send(
+            &app,
+            "POST",
+            "/reflect-with-evidence",
+            serde_json::json!({ "query": "Spike binding" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (reflection, confidence) = combined(&default);
+        assert!((confidence - (0.5 * reflection + 0.5 * 0.9)).abs() < 1e-4);
+
+        let (status, weighted) = send(
+            &app,
+            "POST",
+            "/reflect-with-evidence",
+            serde_json::json!({ "query": "Spike binding", "reflection_weight": 0.8 }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (reflection, confidence) = combined(&weighted);
+        assert!((confidence - (0.8 * reflection + 0.2 * 0.9)).abs() < 1e-4);
+
+        let (_, max) = send(
+            &app,
+            "POST",
+            "/reflect-with-evidence",
+            serde_json::json!({ "query": "Spike binding", "reflection_weight": 0.0, "aggregation": "max" }),
+        )
+        .await;
+        assert!((combined(&max).1 - 1.0).abs() < 1e-4);
+
+        let (status, _) = send(
+            &app,
+            "POST",
+            "/reflect-with-evidence",
+            serde_json::json!({ "query": "Spike binding", "reflection_weight": 1.5 }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_reflection_errors_map_to_client_statuses() {
+        let mut state = CombinedHubState::new();
+        state.reflection_engine.max_total_steps = Some(3);
+        let app = create_combined_router_with_state(state);
+
+        for uri in ["/reflect", "/reflect-with-evidence"] {
+            let (status, _) = send(&app, "POST", uri, serde_json::json!({ "query": "  " })).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", uri);
+        }
+
+        let body = serde_json::json!({ "query": "Spike binding" });
+        let (status, _) = send(&app, "POST", "/reflect", body.clone()).await;
+        assert_eq!(status, StatusCode::OK);
+        for uri in ["/reflect", "/reflect-with-evidence"] {
+            let (status, _) = send(&app, "POST", uri, body.clone()).await;
+            assert_eq!(status, StatusCode::TOO_MANY_REQUESTS, "{}", uri);
+        }
+    }
+}
@@ -1,42 +1,211 @@
 // crates/limit-hub/src/api.rs
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
     routing::{get, post},
     Router,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::io;
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use tower_http::limit::RequestBodyLimitLayer;
 
-use crate::governance::{GovernanceRules, Submission, ValidationResult};
+use crate::calibration::CalibrationReport;
+use crate::citations::CitationGraph;
+use crate::governance::{CompositeWeights, GovernanceRules, Submission, SubmissionId, ValidationResult};
+use crate::session::{AuditAction, AuditEntry, SessionSnapshot};
+use crate::store::{InMemoryStore, SubmissionStore};
+
+/// Default cap on request bodies; larger requests are rejected with 413
+pub const DEFAULT_MAX_BODY_BYTES: usize = 1024 * 1024;
 
 /// Hub API state
+///
+/// Serializes as a `SessionSnapshot` with the store's submissions inlined;
+/// deserializing yields an in-memory store.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "SessionSnapshot")]
 pub struct HubState {
     pub governance: GovernanceRules,
-    pub submissions: Vec<Submission>,
+    pub store: Box<dyn SubmissionStore>,
+    /// Token similarity above which a new submission is flagged as a near-duplicate
+    pub similarity_threshold: f32,
+    /// Request body size limit applied by the router
+    pub max_body_bytes: usize,
+    /// Every accepted submit, update and delete, oldest first
+    pub audit_log: Vec<AuditEntry>,
+}
+
+impl TryFrom<SessionSnapshot> for HubState {
+    type Error = io::Error;
+
+    fn try_from(snapshot: SessionSnapshot) -> io::Result<Self> {
+        let mut state = HubState::new();
+        state.import_session(snapshot)?;
+        Ok(state)
+    }
+}
+
+impl Serialize for HubState {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.export_session().serialize(serializer)
+    }
 }
 
 impl HubState {
     pub fn new() -> Self {
+        Self::with_store(Box::new(InMemoryStore::new()))
+    }
+
+    /// Hub backed by a custom submission store (e.g. a `FileStore`)
+    pub fn with_store(store: Box<dyn SubmissionStore>) -> Self {
         Self {
             governance: GovernanceRules::default_rules(),
-            submissions: vec![],
+            store,
+            similarity_threshold: 0.7,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            audit_log: vec![],
+        }
+    }
+
+    pub fn with_max_body_bytes(mut self, max_body_bytes: usize) -> Self {
+        self.max_body_bytes = max_body_bytes;
+        self
+    }
+
+    /// Capture rules, submissions and audit log so a reviewer can reproduce
+    /// the exact state behind a decision
+    pub fn export_session(&self) -> SessionSnapshot {
+        SessionSnapshot {
+            governance: self.governance.clone(),
+            submissions: self.store.list(),
+            audit_log: self.audit_log.clone(),
+            similarity_threshold: self.similarity_threshold,
+            max_body_bytes: self.max_body_bytes,
         }
     }
+
+    /// Replace this hub's rules, submissions and audit log with a snapshot's;
+    /// the store keeps its backend (e.g. a `FileStore` is rewritten). A
+    /// snapshot with duplicate submission ids, or a failed store write,
+    /// leaves the hub untouched.
+    pub fn import_session(&mut self, snapshot: SessionSnapshot) -> io::Result<()> {
+        let mut staged = InMemoryStore::new();
+        for submission in snapshot.submissions {
+            let id = submission.id.clone();
+            if !staged.insert(submission)? {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("duplicate submission id {} in snapshot", id),
+                ));
+            }
+        }
+        self.store.replace_all(staged.list())?;
+        self.governance = snapshot.governance;
+        self.audit_log = snapshot.audit_log;
+        self.similarity_threshold = snapshot.similarity_threshold;
+        self.max_body_bytes = snapshot.max_body_bytes;
+        Ok(())
+    }
+
+    fn record(&mut self, action: AuditAction, submission: &Submission) {
+        self.audit_log.push(AuditEntry {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            action,
+            submission_id: submission.id.clone(),
+            version: submission.version,
+        });
+    }
+
+    /// Serialize rules and submissions as compact JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Serialize rules and submissions as indented JSON for reviewers
+    pub fn to_json_pretty(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// How stored submissions' self-reported confidence compares with their
+    /// quality score, by confidence decile
+    pub fn confidence_calibration(&self) -> CalibrationReport {
+        CalibrationReport::from_submissions(&self.store.list())
+    }
+
+    /// Stored submissions linked by the provenance sources they share
+    pub fn citation_graph(&self) -> CitationGraph {
+        CitationGraph::from_submissions(&self.store.list())
+    }
+
+    /// Validate each submission against the current rules without storing
+    /// any, so a dataset can be pre-checked before formal submission
+    pub fn simulate_batch(&self, submissions: &[Submission]) -> Vec<ValidationResult> {
+        submissions
+            .iter()
+            .map(|s| self.governance.validate_submission(s))
+            .collect()
+    }
+
+    /// Find stored submissions whose content is similar (token Jaccard) to
+    /// the given one, most similar first
+    pub fn find_similar(&self, submission: &Submission, threshold: f32) -> Vec<(String, f32)> {
+        let tokens = content_tokens(&submission.content);
+        let mut similar: Vec<(String, f32)> = self
+            .store
+            .list()
+            .iter()
+            .filter(|s| s.id != submission.id)
+            .map(|s| (s.id.clone(), jaccard(&tokens, &content_tokens(&s.content))))
+            .filter(|(_, similarity)| *similarity >= threshold)
+            .collect();
+        similar.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        similar
+    }
+}
+
+fn content_tokens(content: &str) -> HashSet<String> {
+    content
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
 }
 
 /// Create Hub API router
 pub fn create_router() -> Router {
-    let state = Arc::new(RwLock::new(HubState::new()));
+    create_router_with_state(HubState::new())
+}
+
+/// Create Hub API router around an existing state, e.g. one with a file-backed store
+pub fn create_router_with_state(state: HubState) -> Router {
+    let body_limit = RequestBodyLimitLayer::new(state.max_body_bytes);
+    let state = Arc::new(RwLock::new(state));
 
     Router::new()
         .route("/health", get(health_check))
         .route("/submit", post(submit_data))
         .route("/submissions", get(list_submissions))
-        .route("/submissions/:id", get(get_submission))
+        .route("/submissions/ranked", get(ranked_submissions))
+        .route(
+            "/submissions/:id",
+            get(get_submission).put(update_submission).delete(delete_submission),
+        )
         .route("/validate", post(validate_submission))
+        .route("/simulate", post(simulate_batch))
+        .route("/session", get(export_session))
+        .layer(body_limit)
         .with_state(state)
 }
 
@@ -49,16 +218,33 @@ async fn health_check() -> Json<HealthResponse> {
 
 async fn submit_data(
     State(state): State<Arc<RwLock<HubState>>>,
-    Json(submission): Json<Submission>,
+    Json(mut submission): Json<Submission>,
 ) -> Result<Json<SubmitResponse>, StatusCode> {
     let mut state = state.write().await;
-    let validation = state.governance.validate_submission(&submission);
+    let mut validation = state.governance.validate_submission(&submission);
 
     if !validation.valid {
         return Err(StatusCode::BAD_REQUEST);
     }
 
-    state.submissions.push(submission.clone());
+    for (id, similarity) in state.find_similar(&submission, state.similarity_threshold) {
+        validation.warnings.push(format!(
+            "Possible duplicate of submission {} (similarity {:.2})",
+            id, similarity
+        ));
+    }
+
+    submission.version = 1;
+    submission.created_at = Some(chrono::Utc::now().to_rfc3339());
+    // Existing ids are only changed through `PUT /submissions/:id`
+    let inserted = state
+        .store
+        .insert(submission.clone())
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if !inserted {
+        return Err(StatusCode::CONFLICT);
+    }
+    state.record(AuditAction::Submitted, &submission);
 
     Ok(Json(SubmitResponse {
         id: submission.id,
@@ -72,7 +258,8 @@ async fn list_submissions(
 ) -> Json<Vec<SubmissionSummary>> {
     let state = state.read().await;
     let summaries = state
-        .submissions
+        .store
+        .list()
         .iter()
         .map(|s| SubmissionSummary {
             id: s.id.clone(),
@@ -84,18 +271,78 @@ async fn list_submissions(
     Json(summaries)
 }
 
+/// Submissions ordered by composite score, strongest evidence first; weights
+/// come from the `confidence`, `quality` and `sources` query params (default 1.0)
+async fn ranked_submissions(
+    State(state): State<Arc<RwLock<HubState>>>,
+    Query(weights): Query<CompositeWeights>,
+) -> Json<Vec<RankedSubmission>> {
+    let state = state.read().await;
+    let mut ranked: Vec<RankedSubmission> = state
+        .store
+        .list()
+        .into_iter()
+        .map(|s| RankedSubmission {
+            score: state.governance.composite_score_with(&s, &weights),
+            submission: s,
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+
+    Json(ranked)
+}
+
 async fn get_submission(
     State(state): State<Arc<RwLock<HubState>>>,
-    Path(id): Path<String>,
+    Path(id): Path<SubmissionId>,
 ) -> Result<Json<Submission>, StatusCode> {
     let state = state.read().await;
+    state.store.get(&id).map(Json).ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn update_submission(
+    State(state): State<Arc<RwLock<HubState>>>,
+    Path(id): Path<SubmissionId>,
+    Json(mut submission): Json<Submission>,
+) -> Result<Json<SubmitResponse>, StatusCode> {
+    let mut state = state.write().await;
+    let validation = state.governance.validate_submission(&submission);
+
+    let existing = state.store.get(&id).ok_or(StatusCode::NOT_FOUND)?;
+
+    if !validation.valid {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    submission.id = id.into();
+    submission.version = existing.version + 1;
+    submission.created_at = existing.created_at;
     state
-        .submissions
-        .iter()
-        .find(|s| s.id == id)
-        .cloned()
-        .map(Json)
-        .ok_or(StatusCode::NOT_FOUND)
+        .store
+        .put(submission.clone())
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    state.record(AuditAction::Updated, &submission);
+
+    Ok(Json(SubmitResponse {
+        id: submission.id,
+        status: "updated".to_string(),
+        validation,
+    }))
+}
+
+async fn delete_submission(
+    State(state): State<Arc<RwLock<HubState>>>,
+    Path(id): Path<SubmissionId>,
+) -> StatusCode {
+    let mut state = state.write().await;
+    match state.store.remove(&id) {
+        Ok(Some(removed)) => {
+            state.record(AuditAction::Deleted, &removed);
+            StatusCode::NO_CONTENT
+        }
+        Ok(None) => StatusCode::NOT_FOUND,
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
 }
 
 async fn validate_submission(
@@ -106,6 +353,21 @@ async fn validate_submission(
     Json(state.governance.validate_submission(&submission))
 }
 
+/// Rules, submissions and audit log, for replay with `import_session`
+async fn export_session(State(state): State<Arc<RwLock<HubState>>>) -> Json<SessionSnapshot> {
+    let state = state.read().await;
+    Json(state.export_session())
+}
+
+/// Validation results for a batch, in request order; nothing is stored
+async fn simulate_batch(
+    State(state): State<Arc<RwLock<HubState>>>,
+    Json(submissions): Json<Vec<Submission>>,
+) -> Json<Vec<ValidationResult>> {
+    let state = state.read().await;
+    Json(state.simulate_batch(&submissions))
+}
+
 #[derive(Debug, Serialize)]
 struct HealthResponse {
     status: String,
@@ -119,6 +381,12 @@ struct SubmitResponse {
     validation: ValidationResult,
 }
 
+#[derive(Debug, Serialize)]
+struct RankedSubmission {
+    score: f32,
+    submission: Submission,
+}
+
 #[derive(Debug, Serialize)]
 struct SubmissionSummary {
     id: String,
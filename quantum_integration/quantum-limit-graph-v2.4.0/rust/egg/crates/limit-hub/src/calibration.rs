@@ -0,0 +1,93 @@
+// crates/limit-hub/src/calibration.rs
+use serde::{Serialize, Deserialize};
+
+use crate::governance::Submission;
+
+/// Gap between mean confidence and mean quality beyond which a bucket is
+/// considered miscalibrated
+pub const CALIBRATION_MISMATCH_THRESHOLD: f32 = 0.25;
+
+/// Submissions whose self-reported confidence falls in `[lower, upper)`
+/// (the top bucket includes 1.0)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationBucket {
+    pub lower: f32,
+    pub upper: f32,
+    pub count: usize,
+    /// `None` for empty buckets
+    pub mean_confidence: Option<f32>,
+    pub mean_quality: Option<f32>,
+}
+
+impl CalibrationBucket {
+    /// Mean confidence minus mean quality; positive when submitters are
+    /// overconfident
+    pub fn gap(&self) -> Option<f32> {
+        Some(self.mean_confidence? - self.mean_quality?)
+    }
+
+    pub fn is_mismatched(&self) -> bool {
+        self.gap()
+            .is_some_and(|gap| gap.abs() > CALIBRATION_MISMATCH_THRESHOLD)
+    }
+}
+
+/// Self-reported confidence against quality score, by confidence decile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationReport {
+    /// Always ten deciles, lowest first
+    pub buckets: Vec<CalibrationBucket>,
+    /// Count-weighted mean of `|gap|` over all buckets (0.0 with no submissions)
+    pub calibration_error: f32,
+}
+
+impl CalibrationReport {
+    /// Bucket submissions by confidence; confidences are clamped into
+    /// `[0, 1]` and NaN confidences or quality scores are skipped
+    pub fn from_submissions(submissions: &[Submission]) -> Self {
+        let mut sums = [(0usize, 0.0f32, 0.0f32); 10];
+        for s in submissions {
+            if s.confidence.is_nan() || s.quality_score.is_nan() {
+                continue;
+            }
+            let confidence = s.confidence.clamp(0.0, 1.0);
+            let decile = ((confidence * 10.0) as usize).min(9);
+            sums[decile].0 += 1;
+            sums[decile].1 += confidence;
+            sums[decile].2 += s.quality_score;
+        }
+
+        let buckets: Vec<CalibrationBucket> = sums
+            .iter()
+            .enumerate()
+            .map(|(decile, &(count, confidence, quality))| CalibrationBucket {
+                lower: decile as f32 / 10.0,
+                upper: (decile + 1) as f32 / 10.0,
+                count,
+                mean_confidence: (count > 0).then(|| confidence / count as f32),
+                mean_quality: (count > 0).then(|| quality / count as f32),
+            })
+            .collect();
+
+        let total: usize = buckets.iter().map(|b| b.count).sum();
+        let calibration_error = if total == 0 {
+            0.0
+        } else {
+            buckets
+                .iter()
+                .filter_map(|b| b.gap().map(|gap| gap.abs() * b.count as f32))
+                .sum::<f32>()
+                / total as f32
+        };
+
+        Self {
+            buckets,
+            calibration_error,
+        }
+    }
+
+    /// Non-empty buckets whose confidence and quality diverge
+    pub fn mismatched(&self) -> Vec<&CalibrationBucket> {
+        self.buckets.iter().filter(|b| b.is_mismatched()).collect()
+    }
+}
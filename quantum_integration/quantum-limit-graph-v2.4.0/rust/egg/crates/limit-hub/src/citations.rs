@@ -0,0 +1,97 @@
+// crates/limit-hub/src/citations.rs
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeSet;
+
+use crate::governance::Submission;
+
+/// Two submissions citing at least one common provenance source
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CitationEdge {
+    pub source: String,
+    pub target: String,
+    /// Sources both submissions cite, sorted
+    pub shared_sources: Vec<String>,
+    /// Number of shared sources
+    pub weight: usize,
+}
+
+/// Submissions linked by shared provenance, for spotting clusters of
+/// related evidence
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CitationGraph {
+    /// Submission ids, in store order
+    pub nodes: Vec<String>,
+    pub edges: Vec<CitationEdge>,
+}
+
+impl CitationGraph {
+    /// Link every pair of submissions sharing a source; sources are compared
+    /// after trimming whitespace and repeated sources count once
+    pub fn from_submissions(submissions: &[Submission]) -> Self {
+        let sources: Vec<BTreeSet<&str>> = submissions
+            .iter()
+            .map(|s| {
+                s.provenance
+                    .iter()
+                    .map(|p| p.trim())
+                    .filter(|p| !p.is_empty())
+                    .collect()
+            })
+            .collect();
+
+        let mut edges = Vec::new();
+        for (i, a) in submissions.iter().enumerate() {
+            for (j, b) in submissions.iter().enumerate().skip(i + 1) {
+                let shared_sources: Vec<String> = sources[i]
+                    .intersection(&sources[j])
+                    .map(|p| p.to_string())
+                    .collect();
+                if !shared_sources.is_empty() {
+                    edges.push(CitationEdge {
+                        source: a.id.clone(),
+                        target: b.id.clone(),
+                        weight: shared_sources.len(),
+                        shared_sources,
+                    });
+                }
+            }
+        }
+
+        Self {
+            nodes: submissions.iter().map(|s| s.id.clone()).collect(),
+            edges,
+        }
+    }
+
+    /// Connected groups of submission ids, largest first (ties keep store
+    /// order); unlinked submissions form singleton clusters
+    pub fn clusters(&self) -> Vec<Vec<String>> {
+        let mut cluster_of: Vec<usize> = (0..self.nodes.len()).collect();
+        let position = |id: &str| self.nodes.iter().position(|n| n == id);
+        for edge in &self.edges {
+            let (Some(a), Some(b)) = (position(&edge.source), position(&edge.target)) else {
+                continue;
+            };
+            let (keep, merge) = (cluster_of[a].min(cluster_of[b]), cluster_of[a].max(cluster_of[b]));
+            for cluster in cluster_of.iter_mut() {
+                if *cluster == merge {
+                    *cluster = keep;
+                }
+            }
+        }
+
+        let mut clusters: Vec<Vec<String>> = Vec::new();
+        let mut roots: Vec<usize> = Vec::new();
+        for (node, &root) in self.nodes.iter().zip(&cluster_of) {
+            match roots.iter().position(|&r| r == root) {
+                Some(i) => clusters[i].push(node.clone()),
+                None => {
+                    roots.push(root);
+                    clusters.push(vec![node.clone()]);
+                }
+            }
+        }
+        clusters.sort_by_key(|c| std::cmp::Reverse(c.len()));
+        clusters
+    }
+}
@@ -0,0 +1,80 @@
+// crates/limit-hub/src/evidence.rs
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use std::cmp::Ordering;
+
+use crate::governance::Submission;
+
+/// Weights for ranking submissions as supporting evidence
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EvidenceWeights {
+    pub confidence: f32,
+    pub recency: f32,
+    /// Age in days at which the recency factor halves
+    pub half_life_days: f32,
+}
+
+impl Default for EvidenceWeights {
+    fn default() -> Self {
+        Self {
+            confidence: 1.0,
+            recency: 0.1,
+            half_life_days: 180.0,
+        }
+    }
+}
+
+impl EvidenceWeights {
+    /// Score a submission; submissions without a `created_at` get no recency credit
+    pub fn score(&self, submission: &Submission, now: DateTime<Utc>) -> f32 {
+        let recency = submission
+            .created_at
+            .as_deref()
+            .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+            .map(|created| {
+                let age_days = (now - created.with_timezone(&Utc)).num_seconds().max(0) as f32 / 86_400.0;
+                0.5_f32.powf(age_days / self.half_life_days.max(f32::EPSILON))
+            })
+            .unwrap_or(0.0);
+
+        self.confidence * submission.confidence + self.recency * recency
+    }
+}
+
+/// How the confidences of supporting evidence are combined into one score
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EvidenceAggregation {
+    #[default]
+    Mean,
+    Max,
+}
+
+impl EvidenceAggregation {
+    /// Aggregate evidence confidences (0.0 when there is no evidence)
+    pub fn aggregate(&self, confidences: impl IntoIterator<Item = f32>) -> f32 {
+        let confidences: Vec<f32> = confidences.into_iter().collect();
+        if confidences.is_empty() {
+            return 0.0;
+        }
+        match self {
+            Self::Mean => confidences.iter().sum::<f32>() / confidences.len() as f32,
+            Self::Max => confidences.iter().copied().fold(f32::MIN, f32::max),
+        }
+    }
+}
+
+/// Rank submissions by descending evidence score
+pub fn rank_evidence<'a>(
+    submissions: &'a [Submission],
+    weights: &EvidenceWeights,
+    now: DateTime<Utc>,
+) -> Vec<&'a Submission> {
+    let mut scored: Vec<(&Submission, f32)> = submissions
+        .iter()
+        .map(|s| (s, weights.score(s, now)))
+        .collect();
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+    scored.into_iter().map(|(s, _)| s).collect()
+}
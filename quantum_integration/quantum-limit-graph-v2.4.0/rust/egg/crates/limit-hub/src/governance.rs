@@ -1,6 +1,10 @@
 // crates/limit-hub/src/governance.rs
+use chrono::{DateTime, NaiveDate, Utc};
+use regex::Regex;
 use serde::{Serialize, Deserialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::OnceLock;
 
 /// Governance rules for open-source hub
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,6 +14,43 @@ pub struct GovernanceRules {
     pub allowed_sources: Vec<String>,
     pub quality_threshold: f32,
     pub review_required: bool,
+    #[serde(default)]
+    pub composite_weights: CompositeWeights,
+    /// Reject submissions whose `composite_score` falls below this, if set
+    #[serde(default)]
+    pub composite_threshold: Option<f32>,
+    /// Trust in [0, 1] per source name; unlisted sources are fully trusted
+    #[serde(default)]
+    pub source_trust: HashMap<String, f32>,
+    /// Warn on provenance entries that are not DOI, PubMed or bioRxiv identifiers
+    #[serde(default)]
+    pub check_provenance_format: bool,
+    /// Reject submissions whose `publication_date` is older than this, if set
+    #[serde(default)]
+    pub max_age_days: Option<u32>,
+    /// With `max_age_days` set, warn about submissions that have no
+    /// `publication_date` (otherwise they pass silently)
+    #[serde(default)]
+    pub warn_undated: bool,
+}
+
+/// Relative weights of the terms in `GovernanceRules::composite_score`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompositeWeights {
+    pub confidence: f32,
+    pub quality: f32,
+    pub sources: f32,
+}
+
+impl Default for CompositeWeights {
+    fn default() -> Self {
+        Self {
+            confidence: 1.0,
+            quality: 1.0,
+            sources: 1.0,
+        }
+    }
 }
 
 impl GovernanceRules {
@@ -24,18 +65,92 @@ impl GovernanceRules {
             ],
             quality_threshold: 0.8,
             review_required: true,
+            composite_weights: CompositeWeights::default(),
+            composite_threshold: None,
+            source_trust: HashMap::new(),
+            check_provenance_format: false,
+            max_age_days: None,
+            warn_undated: false,
+        }
+    }
+
+    /// Trust assigned to a provenance entry, matched on its full text or on the
+    /// source name before a `:` (e.g. `PubMed:12345` -> `PubMed`)
+    pub fn trust_for(&self, provenance: &str) -> f32 {
+        let source = provenance.split(':').next().unwrap_or(provenance);
+        self.source_trust
+            .get(provenance)
+            .or_else(|| self.source_trust.get(source))
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    /// Self-reported confidence scaled by the most trusted provenance source
+    pub fn effective_confidence(&self, submission: &Submission) -> f32 {
+        if self.source_trust.is_empty() || submission.provenance.is_empty() {
+            return submission.confidence;
         }
+
+        let best_trust = submission
+            .provenance
+            .iter()
+            .map(|p| self.trust_for(p))
+            .fold(0.0_f32, f32::max);
+        submission.confidence * best_trust
+    }
+
+    /// Weighted mean of confidence, quality score and distinct-source coverage
+    /// (distinct provenance entries relative to `min_provenance_count`, capped at 1)
+    pub fn composite_score(&self, submission: &Submission) -> f32 {
+        self.composite_score_with(submission, &self.composite_weights)
+    }
+
+    /// `composite_score` with caller-supplied weights
+    pub fn composite_score_with(&self, submission: &Submission, weights: &CompositeWeights) -> f32 {
+        let total_weight = weights.confidence + weights.quality + weights.sources;
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+
+        let distinct_sources = submission.provenance.iter().collect::<HashSet<_>>().len();
+        let source_coverage =
+            (distinct_sources as f32 / self.min_provenance_count.max(1) as f32).min(1.0);
+
+        (weights.confidence * submission.confidence
+            + weights.quality * submission.quality_score
+            + weights.sources * source_coverage)
+            / total_weight
+    }
+
+    /// Warnings for provenance entries that do not look like a DOI
+    /// (including bioRxiv/medRxiv `10.1101/...` DOIs), a PubMed or PMC ID,
+    /// or a link to one of those
+    pub fn validate_provenance_format(&self, submission: &Submission) -> Vec<String> {
+        submission
+            .provenance
+            .iter()
+            .filter(|p| !provenance_patterns().iter().any(|re| re.is_match(p.trim())))
+            .map(|p| format!("Provenance '{}' is not a recognised DOI, PubMed or bioRxiv identifier", p))
+            .collect()
     }
 
     pub fn validate_submission(&self, submission: &Submission) -> ValidationResult {
         let mut errors = Vec::new();
         let mut warnings = Vec::new();
 
-        if submission.confidence < self.min_confidence {
-            errors.push(format!(
-                "Confidence {} below minimum {}",
-                submission.confidence, self.min_confidence
-            ));
+        let confidence = self.effective_confidence(submission);
+        if confidence < self.min_confidence {
+            if confidence < submission.confidence {
+                errors.push(format!(
+                    "Effective confidence {:.3} (confidence {} weighted by source trust) below minimum {}",
+                    confidence, submission.confidence, self.min_confidence
+                ));
+            } else {
+                errors.push(format!(
+                    "Confidence {} below minimum {}",
+                    submission.confidence, self.min_confidence
+                ));
+            }
         }
 
         if submission.provenance.len() < self.min_provenance_count {
@@ -52,6 +167,27 @@ impl GovernanceRules {
             }
         }
 
+        if self.check_provenance_format {
+            warnings.extend(self.validate_provenance_format(submission));
+        }
+
+        if let Some(max_age_days) = self.max_age_days {
+            match submission.publication_date.as_deref() {
+                Some(date) => match age_in_days(date, Utc::now()) {
+                    Some(age) if age > i64::from(max_age_days) => errors.push(format!(
+                        "Publication date {} is {} days old, beyond maximum age {}",
+                        date, age, max_age_days
+                    )),
+                    Some(_) => {}
+                    None => errors.push(format!("Publication date '{}' is not a valid date", date)),
+                },
+                None if self.warn_undated => {
+                    warnings.push("No publication date to check against maximum age".to_string())
+                }
+                None => {}
+            }
+        }
+
         if submission.quality_score < self.quality_threshold {
             warnings.push(format!(
                 "Quality score {} below threshold {}",
@@ -59,6 +195,16 @@ impl GovernanceRules {
             ));
         }
 
+        if let Some(threshold) = self.composite_threshold {
+            let score = self.composite_score(submission);
+            if score < threshold {
+                errors.push(format!(
+                    "Composite score {:.3} below threshold {}",
+                    score, threshold
+                ));
+            }
+        }
+
         ValidationResult {
             valid: errors.is_empty(),
             errors,
@@ -68,6 +214,77 @@ impl GovernanceRules {
     }
 }
 
+/// Days elapsed since an RFC 3339 timestamp or `YYYY-MM-DD` date, if it parses
+fn age_in_days(date: &str, now: DateTime<Utc>) -> Option<i64> {
+    let published = DateTime::parse_from_rfc3339(date)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|dt| dt.and_utc())
+        })?;
+    Some((now - published).num_days())
+}
+
+/// Accepted provenance identifier formats, compiled once
+fn provenance_patterns() -> &'static [Regex] {
+    static PATTERNS: OnceLock<Vec<Regex>> = OnceLock::new();
+    PATTERNS.get_or_init(|| {
+        [
+            // DOI, bare or as doi: / doi.org link
+            r"^(?i:doi:\s*|https?://(dx\.)?doi\.org/)?10\.\d{4,9}/\S+$",
+            // bioRxiv / medRxiv content links
+            r"^(?i)https?://(www\.)?(bio|med)rxiv\.org/content/10\.1101/\S+$",
+            // PubMed ID, bare or prefixed, and PubMed links
+            r"^(?i:(PMID|PubMed)\s*:?\s*)?\d{1,8}$",
+            r"^(?i)https?://pubmed\.ncbi\.nlm\.nih\.gov/\d{1,8}/?$",
+            // PubMed Central ID
+            r"^(?i)PMC\d+$",
+        ]
+        .iter()
+        .map(|pattern| Regex::new(pattern).expect("valid provenance pattern"))
+        .collect()
+    })
+}
+
+/// Identifier of a hub submission, distinct from graph node ids; serializes
+/// as a bare string
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SubmissionId(pub String);
+
+impl SubmissionId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SubmissionId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
+impl From<&str> for SubmissionId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl From<SubmissionId> for String {
+    fn from(id: SubmissionId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for SubmissionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Submission {
     pub id: String,
@@ -76,6 +293,15 @@ pub struct Submission {
     pub provenance: Vec<String>,
     pub quality_score: f32,
     pub metadata: HashMap<String, String>,
+    /// Incremented by the hub on every accepted update (1 on first submit)
+    #[serde(default)]
+    pub version: u32,
+    /// RFC 3339 timestamp set by the hub when first stored
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// When the cited data was published (RFC 3339 or `YYYY-MM-DD`)
+    #[serde(default)]
+    pub publication_date: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
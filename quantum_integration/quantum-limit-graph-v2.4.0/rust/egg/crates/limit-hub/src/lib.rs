@@ -1,6 +1,16 @@
 // crates/limit-hub/src/lib.rs
 pub mod governance;
+pub mod evidence;
+pub mod calibration;
+pub mod citations;
+pub mod store;
+pub mod session;
 pub mod api;
 
-pub use governance::{GovernanceRules, Submission, ValidationResult};
-pub use api::{create_router, HubState};
+pub use governance::{CompositeWeights, GovernanceRules, Submission, SubmissionId, ValidationResult};
+pub use calibration::{CalibrationBucket, CalibrationReport, CALIBRATION_MISMATCH_THRESHOLD};
+pub use citations::{CitationEdge, CitationGraph};
+pub use evidence::{rank_evidence, EvidenceAggregation, EvidenceWeights};
+pub use session::{AuditAction, AuditEntry, SessionSnapshot};
+pub use store::{FileStore, InMemoryStore, SubmissionStore};
+pub use api::{create_router, create_router_with_state, HubState, DEFAULT_MAX_BODY_BYTES};
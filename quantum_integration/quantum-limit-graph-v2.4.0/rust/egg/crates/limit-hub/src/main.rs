@@ -3,7 +3,11 @@ use axum::Router;
 use tracing_subscriber;
 
 mod api;
+mod calibration;
+mod citations;
 mod governance;
+mod session;
+mod store;
 
 #[tokio::main]
 async fn main() {
@@ -0,0 +1,43 @@
+// crates/limit-hub/src/session.rs
+use serde::{Serialize, Deserialize};
+
+use crate::governance::{GovernanceRules, Submission};
+
+/// Change made to the hub's submissions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Submitted,
+    Updated,
+    Deleted,
+}
+
+/// One accepted change, in the order the hub applied it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// RFC 3339 timestamp
+    pub timestamp: String,
+    pub action: AuditAction,
+    pub submission_id: String,
+    /// Version of the submission after the change (its last version when deleted)
+    pub version: u32,
+}
+
+/// Everything needed to reproduce a hub's state: rules, submissions and the
+/// audit log of how they got there
+///
+/// This is also the serialized form of `HubState`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    pub governance: GovernanceRules,
+    pub submissions: Vec<Submission>,
+    #[serde(default)]
+    pub audit_log: Vec<AuditEntry>,
+    pub similarity_threshold: f32,
+    #[serde(default = "default_max_body_bytes")]
+    pub max_body_bytes: usize,
+}
+
+fn default_max_body_bytes() -> usize {
+    crate::api::DEFAULT_MAX_BODY_BYTES
+}
@@ -0,0 +1,146 @@
+// crates/limit-hub/src/store.rs
+use std::fmt::Debug;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::governance::{Submission, SubmissionId};
+
+/// Storage backend for hub submissions; all CRUD in the API goes through it
+pub trait SubmissionStore: Debug + Send + Sync {
+    /// Insert a submission, replacing any stored one with the same id
+    fn put(&mut self, submission: Submission) -> io::Result<()>;
+
+    /// Insert a submission only if no stored one has the same id; returns
+    /// `false`, storing nothing, when the id is already taken
+    fn insert(&mut self, submission: Submission) -> io::Result<bool> {
+        if self.get(&SubmissionId::from(submission.id.clone())).is_some() {
+            return Ok(false);
+        }
+        self.put(submission)?;
+        Ok(true)
+    }
+
+    fn get(&self, id: &SubmissionId) -> Option<Submission>;
+
+    /// Remove a submission, returning it if it was stored
+    fn remove(&mut self, id: &SubmissionId) -> io::Result<Option<Submission>>;
+
+    /// All submissions in insertion order
+    fn list(&self) -> Vec<Submission>;
+
+    /// Replace every stored submission in one step; on error the store is
+    /// left as it was
+    fn replace_all(&mut self, submissions: Vec<Submission>) -> io::Result<()>;
+}
+
+/// Default store: submissions held in memory only
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryStore {
+    submissions: Vec<Submission>,
+}
+
+impl InMemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SubmissionStore for InMemoryStore {
+    fn put(&mut self, submission: Submission) -> io::Result<()> {
+        match self.submissions.iter_mut().find(|s| s.id == submission.id) {
+            Some(existing) => *existing = submission,
+            None => self.submissions.push(submission),
+        }
+        Ok(())
+    }
+
+    fn get(&self, id: &SubmissionId) -> Option<Submission> {
+        self.submissions.iter().find(|s| s.id == id.as_str()).cloned()
+    }
+
+    fn remove(&mut self, id: &SubmissionId) -> io::Result<Option<Submission>> {
+        Ok(self
+            .submissions
+            .iter()
+            .position(|s| s.id == id.as_str())
+            .map(|idx| self.submissions.remove(idx)))
+    }
+
+    fn list(&self) -> Vec<Submission> {
+        self.submissions.clone()
+    }
+
+    fn replace_all(&mut self, submissions: Vec<Submission>) -> io::Result<()> {
+        self.submissions = submissions;
+        Ok(())
+    }
+}
+
+/// File-backed store: submissions are cached in memory and the whole set is
+/// rewritten as JSON after every change, so they survive restarts. The cache
+/// only changes once the write succeeds, so memory and disk never disagree
+#[derive(Debug)]
+pub struct FileStore {
+    path: PathBuf,
+    cache: InMemoryStore,
+}
+
+impl FileStore {
+    /// Open the store at `path`, loading existing submissions if the file exists
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let submissions = if path.exists() {
+            serde_json::from_str(&fs::read_to_string(&path)?)?
+        } else {
+            vec![]
+        };
+
+        Ok(Self {
+            path,
+            cache: InMemoryStore { submissions },
+        })
+    }
+
+    /// Write to a sibling temp file and rename, so a crash never leaves a
+    /// half-written store behind
+    fn persist(&self, submissions: &[Submission]) -> io::Result<()> {
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(submissions)?)?;
+        fs::rename(tmp, &self.path)
+    }
+}
+
+impl SubmissionStore for FileStore {
+    fn put(&mut self, submission: Submission) -> io::Result<()> {
+        let mut next = self.cache.clone();
+        next.put(submission)?;
+        self.persist(&next.submissions)?;
+        self.cache = next;
+        Ok(())
+    }
+
+    fn get(&self, id: &SubmissionId) -> Option<Submission> {
+        self.cache.get(id)
+    }
+
+    fn remove(&mut self, id: &SubmissionId) -> io::Result<Option<Submission>> {
+        let mut next = self.cache.clone();
+        let removed = next.remove(id)?;
+        if removed.is_some() {
+            self.persist(&next.submissions)?;
+            self.cache = next;
+        }
+        Ok(removed)
+    }
+
+    fn list(&self) -> Vec<Submission> {
+        self.cache.list()
+    }
+
+    fn replace_all(&mut self, submissions: Vec<Submission>) -> io::Result<()> {
+        self.persist(&submissions)?;
+        self.cache = InMemoryStore { submissions };
+        Ok(())
+    }
+}
@@ -0,0 +1,436 @@
+// tests/api_tests.rs
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use limit_hub::{
+        create_router, create_router_with_state, AuditAction, FileStore, HubState, InMemoryStore, SessionSnapshot,
+        Submission, SubmissionId, SubmissionStore,
+    };
+    use serde_json::Value;
+    use std::collections::HashMap;
+    use tower::ServiceExt;
+
+    fn create_test_submission(id: &str, content: &str) -> Submission {
+        Submission {
+            id: id.to_string(),
+            content: content.to_string(),
+            confidence: 0.85,
+            provenance: vec!["PubMed".to_string(), "bioRxiv".to_string()],
+            quality_score: 0.9,
+            metadata: HashMap::new(),
+            version: 0,
+            created_at: None,
+            publication_date: None,
+        }
+    }
+
+    async fn send(app: &Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(match body {
+                Some(json) => Body::from(json.to_string()),
+                None => Body::empty(),
+            })
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let json = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+        (status, json)
+    }
+
+    #[tokio::test]
+    async fn test_update_submission_increments_version() {
+        let app = create_router();
+        let original = create_test_submission("sub-001", "Original finding");
+
+        let (status, _) = send(&app, "POST", "/submit", Some(serde_json::to_value(&original).unwrap())).await;
+        assert_eq!(status, StatusCode::OK);
+        let (_, created) = send(&app, "GET", "/submissions/sub-001", None).await;
+        assert_eq!(created["version"], 1);
+
+        let updated = create_test_submission("sub-001", "Corrected finding");
+        let (status, _) = send(&app, "PUT", "/submissions/sub-001", Some(serde_json::to_value(&updated).unwrap())).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (_, stored) = send(&app, "GET", "/submissions/sub-001", None).await;
+        assert_eq!(stored["version"], 2);
+        assert_eq!(stored["content"], "Corrected finding");
+        assert_eq!(stored["created_at"], created["created_at"]);
+    }
+
+    #[tokio::test]
+    async fn test_update_submission_rejects_invalid() {
+        let app = create_router();
+        let original = create_test_submission("sub-001", "Original finding");
+        send(&app, "POST", "/submit", Some(serde_json::to_value(&original).unwrap())).await;
+
+        let mut invalid = create_test_submission("sub-001", "Weak finding");
+        invalid.confidence = 0.1;
+        let (status, _) = send(&app, "PUT", "/submissions/sub-001", Some(serde_json::to_value(&invalid).unwrap())).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (_, stored) = send(&app, "GET", "/submissions/sub-001", None).await;
+        assert_eq!(stored["version"], 1);
+        assert_eq!(stored["content"], "Original finding");
+    }
+
+    #[test]
+    fn test_find_similar_paraphrase() {
+        let mut state = HubState::new();
+        state
+            .store
+            .put(create_test_submission(
+                "sub-001",
+                "The spike protein binds the ACE2 receptor with high affinity",
+            ))
+            .unwrap();
+        state
+            .store
+            .put(create_test_submission(
+                "sub-002",
+                "Remdesivir shortens hospital stay in severe cases",
+            ))
+            .unwrap();
+
+        let paraphrase = create_test_submission(
+            "sub-003",
+            "Spike protein binds to ACE2 receptor with high affinity.",
+        );
+        let similar = state.find_similar(&paraphrase, 0.6);
+
+        assert_eq!(similar.len(), 1);
+        assert_eq!(similar[0].0, "sub-001");
+        assert!(similar[0].1 >= 0.6);
+    }
+
+    #[tokio::test]
+    async fn test_submit_warns_on_near_duplicate() {
+        let app = create_router();
+        let first = create_test_submission("sub-001", "The spike protein binds the ACE2 receptor with high affinity");
+        send(&app, "POST", "/submit", Some(serde_json::to_value(&first).unwrap())).await;
+
+        let second = create_test_submission("sub-002", "Spike protein binds the ACE2 receptor with high affinity");
+        let (status, response) = send(&app, "POST", "/submit", Some(serde_json::to_value(&second).unwrap())).await;
+
+        assert_eq!(status, StatusCode::OK);
+        let warnings = response["validation"]["warnings"].as_array().unwrap();
+        assert!(warnings
+            .iter()
+            .any(|w| w.as_str().unwrap().contains("Possible duplicate of submission sub-001")));
+    }
+
+    #[tokio::test]
+    async fn test_simulate_batch_stores_nothing() {
+        let state = HubState::new();
+        let mut low_confidence = create_test_submission("sub-002", "Weak claim");
+        low_confidence.confidence = 0.1;
+        let mut unsourced = create_test_submission("sub-003", "Unsourced claim");
+        unsourced.provenance.clear();
+        let batch = vec![create_test_submission("sub-001", "Spike binds ACE2"), low_confidence, unsourced];
+
+        let expected: Vec<Value> = batch
+            .iter()
+            .map(|s| serde_json::to_value(state.governance.validate_submission(s)).unwrap())
+            .collect();
+        let results = state.simulate_batch(&batch);
+        assert_eq!(results.iter().map(|r| r.valid).collect::<Vec<_>>(), vec![true, false, false]);
+
+        let app = create_router_with_state(state);
+        let (status, body) = send(&app, "POST", "/simulate", Some(serde_json::to_value(&batch).unwrap())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, Value::Array(expected));
+
+        let (_, submissions) = send(&app, "GET", "/submissions", None).await;
+        assert_eq!(submissions, Value::Array(vec![]));
+    }
+
+    #[test]
+    fn test_citation_graph_links_shared_sources() {
+        let mut state = HubState::new();
+        for (id, provenance) in [
+            ("sub-001", vec!["PubMed:12345", "Nature:2020"]),
+            ("sub-002", vec!["bioRxiv:777", " PubMed:12345"]),
+            ("sub-003", vec!["Lancet:2021"]),
+        ] {
+            let mut submission = create_test_submission(id, "Finding");
+            submission.provenance = provenance.into_iter().map(String::from).collect();
+            state.store.put(submission).unwrap();
+        }
+
+        let graph = state.citation_graph();
+        assert_eq!(graph.nodes.len(), 3);
+        assert_eq!(graph.edges.len(), 1);
+        let edge = &graph.edges[0];
+        assert_eq!((edge.source.as_str(), edge.target.as_str()), ("sub-001", "sub-002"));
+        assert_eq!(edge.shared_sources, vec!["PubMed:12345"]);
+        assert_eq!(edge.weight, 1);
+
+        assert_eq!(
+            graph.clusters(),
+            vec![vec!["sub-001".to_string(), "sub-002".to_string()], vec!["sub-003".to_string()]]
+        );
+    }
+
+    #[test]
+    fn test_confidence_calibration_flags_mismatch() {
+        let mut state = HubState::new();
+        // Well calibrated around 0.5, overconfident at the top
+        for (id, confidence, quality) in [
+            ("sub-001", 0.55, 0.5),
+            ("sub-002", 0.52, 0.57),
+            ("sub-003", 0.95, 0.3),
+            ("sub-004", 1.0, 0.2),
+        ] {
+            let mut submission = create_test_submission(id, "Finding");
+            submission.confidence = confidence;
+            submission.quality_score = quality;
+            state.store.put(submission).unwrap();
+        }
+
+        let report = state.confidence_calibration();
+        assert_eq!(report.buckets.len(), 10);
+        assert_eq!(report.buckets.iter().map(|b| b.count).sum::<usize>(), 4);
+
+        let middle = &report.buckets[5];
+        assert_eq!(middle.count, 2);
+        assert!(middle.gap().unwrap().abs() < 0.01);
+        assert!(!middle.is_mismatched());
+
+        let top = &report.buckets[9];
+        assert_eq!(top.count, 2);
+        assert!((top.gap().unwrap() - 0.725).abs() < 1e-5);
+        assert_eq!(report.mismatched(), vec![top]);
+        assert_eq!(report.buckets[0].gap(), None);
+        assert!(report.calibration_error > 0.3);
+    }
+
+    #[tokio::test]
+    async fn test_update_unknown_submission() {
+        let app = create_router();
+        let submission = create_test_submission("missing", "Nothing here");
+
+        let (status, _) = send(&app, "PUT", "/submissions/missing", Some(serde_json::to_value(&submission).unwrap())).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_ranked_submissions() {
+        let app = create_router();
+        for (id, quality, content) in [
+            ("mid", 0.85, "Omicron shows immune escape"),
+            ("high", 0.99, "Spike binds ACE2"),
+            ("low", 0.7, "Remdesivir shortens recovery"),
+        ] {
+            let mut submission = create_test_submission(id, content);
+            submission.quality_score = quality;
+            let (status, _) = send(&app, "POST", "/submit", Some(serde_json::to_value(&submission).unwrap())).await;
+            assert_eq!(status, StatusCode::OK);
+        }
+
+        let (status, body) = send(&app, "GET", "/submissions/ranked", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let ids: Vec<&str> = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r["submission"]["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["high", "mid", "low"]);
+
+        // Ignoring quality leaves every submission tied on confidence and sources
+        let (_, body) = send(&app, "GET", "/submissions/ranked?quality=0", None).await;
+        let scores: Vec<f64> = body.as_array().unwrap().iter().map(|r| r["score"].as_f64().unwrap()).collect();
+        assert!(scores.iter().all(|s| (s - scores[0]).abs() < 1e-6));
+    }
+
+    #[tokio::test]
+    async fn test_file_store_persists_across_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("submissions.json");
+
+        let store = FileStore::open(&path).unwrap();
+        let app = create_router_with_state(HubState::with_store(Box::new(store)));
+        for (id, content) in [("sub-001", "Spike binds ACE2"), ("sub-002", "Omicron escapes antibodies")] {
+            let submission = create_test_submission(id, content);
+            let (status, _) = send(&app, "POST", "/submit", Some(serde_json::to_value(&submission).unwrap())).await;
+            assert_eq!(status, StatusCode::OK);
+        }
+
+        let (status, stored) = send(&app, "GET", "/submissions/sub-001", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stored["content"], "Spike binds ACE2");
+
+        let (status, _) = send(&app, "DELETE", "/submissions/sub-002", None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = send(&app, "DELETE", "/submissions/sub-002", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        drop(app);
+
+        let reopened = FileStore::open(&path).unwrap();
+        let ids: Vec<String> = reopened.list().into_iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec!["sub-001"]);
+        assert_eq!(reopened.get(&"sub-001".into()).unwrap().version, 1);
+    }
+
+    #[tokio::test]
+    async fn test_resubmitting_existing_id_conflicts() {
+        let app = create_router();
+        let original = create_test_submission("sub-001", "Original finding");
+        let (status, _) = send(&app, "POST", "/submit", Some(serde_json::to_value(&original).unwrap())).await;
+        assert_eq!(status, StatusCode::OK);
+        let updated = create_test_submission("sub-001", "Corrected finding");
+        send(&app, "PUT", "/submissions/sub-001", Some(serde_json::to_value(&updated).unwrap())).await;
+        let (_, before) = send(&app, "GET", "/submissions/sub-001", None).await;
+
+        let replacement = create_test_submission("sub-001", "Replacement finding");
+        let (status, _) = send(&app, "POST", "/submit", Some(serde_json::to_value(&replacement).unwrap())).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let (_, after) = send(&app, "GET", "/submissions/sub-001", None).await;
+        assert_eq!(after, before);
+        assert_eq!(after["version"], 2);
+    }
+
+    #[test]
+    fn test_file_store_keeps_cache_when_write_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("submissions.json");
+        let mut store = FileStore::open(&path).unwrap();
+        store.put(create_test_submission("sub-001", "Spike binds ACE2")).unwrap();
+
+        // A directory where the temp file goes makes every write fail
+        std::fs::create_dir(path.with_extension("tmp")).unwrap();
+        assert!(store.put(create_test_submission("sub-002", "Omicron escapes antibodies")).is_err());
+        assert!(store.remove(&"sub-001".into()).is_err());
+
+        let ids: Vec<String> = store.list().into_iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec!["sub-001"]);
+        let on_disk: Vec<String> = FileStore::open(&path).unwrap().list().into_iter().map(|s| s.id).collect();
+        assert_eq!(on_disk, ids);
+    }
+
+    #[test]
+    fn test_submission_id_lookup_and_serde() {
+        let mut store = InMemoryStore::new();
+        store.put(create_test_submission("sub-001", "Spike binds ACE2")).unwrap();
+
+        let id = SubmissionId::from("sub-001");
+        assert_eq!(store.get(&id).unwrap().content, "Spike binds ACE2");
+        assert!(store.get(&SubmissionId::from("sub-404")).is_none());
+
+        // Serializes as the bare string, so existing payloads stay compatible
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"sub-001\"");
+        assert_eq!(serde_json::from_str::<SubmissionId>(&json).unwrap(), id);
+        assert_eq!(id.to_string(), "sub-001");
+
+        assert!(store.remove(&id).unwrap().is_some());
+        assert!(store.get(&id).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_rejected() {
+        let app = create_router_with_state(HubState::new().with_max_body_bytes(1024));
+
+        let submission = create_test_submission("sub-big", &"spike ".repeat(1000));
+        let (status, _) = send(&app, "POST", "/submit", Some(serde_json::to_value(&submission).unwrap())).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+
+        let (status, _) = send(&app, "GET", "/submissions/sub-big", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_session_export_and_import() {
+        let mut state = HubState::new();
+        state.governance.min_confidence = 0.8;
+        let app = create_router_with_state(state);
+        for (id, content) in [("sub-001", "Spike binds ACE2"), ("sub-002", "Omicron escapes antibodies")] {
+            let submission = create_test_submission(id, content);
+            send(&app, "POST", "/submit", Some(serde_json::to_value(&submission).unwrap())).await;
+        }
+        let updated = create_test_submission("sub-001", "Spike binds ACE2 strongly");
+        send(&app, "PUT", "/submissions/sub-001", Some(serde_json::to_value(&updated).unwrap())).await;
+        send(&app, "DELETE", "/submissions/sub-002", None).await;
+
+        let (status, body) = send(&app, "GET", "/session", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let exported: SessionSnapshot = serde_json::from_value(body).unwrap();
+        let actions: Vec<AuditAction> = exported.audit_log.iter().map(|e| e.action).collect();
+        assert_eq!(
+            actions,
+            vec![AuditAction::Submitted, AuditAction::Submitted, AuditAction::Updated, AuditAction::Deleted]
+        );
+
+        // Round-trip through JSON into a fresh hub
+        let json = serde_json::to_string(&exported).unwrap();
+        let mut restored = HubState::new();
+        restored.store.put(create_test_submission("stale", "Replaced on import")).unwrap();
+        restored
+            .import_session(serde_json::from_str::<SessionSnapshot>(&json).unwrap())
+            .unwrap();
+
+        let replayed = restored.export_session();
+        assert_eq!(serde_json::to_value(&replayed).unwrap(), serde_json::to_value(&exported).unwrap());
+        assert_eq!(restored.governance.min_confidence, 0.8);
+        let ids: Vec<String> = restored.store.list().into_iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec!["sub-001"]);
+        assert_eq!(restored.store.get(&"sub-001".into()).unwrap().version, 2);
+        assert_eq!(restored.audit_log, exported.audit_log);
+    }
+
+    #[test]
+    fn test_failed_import_leaves_hub_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("submissions.json");
+        let mut state = HubState::with_store(Box::new(FileStore::open(&path).unwrap()));
+        state.store.put(create_test_submission("sub-001", "Spike binds ACE2")).unwrap();
+
+        let mut snapshot = HubState::new().export_session();
+        snapshot.governance.min_confidence = 0.99;
+        snapshot.submissions = vec![
+            create_test_submission("sub-002", "Omicron escapes antibodies"),
+            create_test_submission("sub-002", "Omicron escapes antibodies again"),
+        ];
+        assert!(state.import_session(snapshot.clone()).is_err());
+        assert!(serde_json::from_value::<HubState>(serde_json::to_value(&snapshot).unwrap()).is_err());
+
+        // A valid snapshot whose write fails
+        snapshot.submissions.pop();
+        std::fs::create_dir(path.with_extension("tmp")).unwrap();
+        assert!(state.import_session(snapshot).is_err());
+
+        assert_ne!(state.governance.min_confidence, 0.99);
+        let ids: Vec<String> = state.store.list().into_iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec!["sub-001"]);
+        let on_disk: Vec<String> = FileStore::open(&path).unwrap().list().into_iter().map(|s| s.id).collect();
+        assert_eq!(on_disk, ids);
+    }
+
+    #[test]
+    fn test_state_json_round_trip() {
+        let mut state = HubState::new();
+        state.store.put(create_test_submission("sub-001", "Spike binds ACE2")).unwrap();
+
+        let compact = state.to_json().unwrap();
+        let pretty = state.to_json_pretty().unwrap();
+
+        assert!(!compact.contains('\n'));
+        assert!(pretty.contains('\n'));
+        for json in [&compact, &pretty] {
+            let restored: HubState = serde_json::from_str(json).unwrap();
+            let stored = restored.store.get(&SubmissionId::from("sub-001")).unwrap();
+            assert_eq!(stored.content, "Spike binds ACE2");
+            assert_eq!(restored.governance.min_confidence, state.governance.min_confidence);
+        }
+    }
+}
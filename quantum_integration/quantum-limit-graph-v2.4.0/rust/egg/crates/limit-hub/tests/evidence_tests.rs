@@ -0,0 +1,60 @@
+// tests/evidence_tests.rs
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use limit_hub::{rank_evidence, EvidenceWeights, Submission};
+    use std::collections::HashMap;
+
+    fn create_test_submission(id: &str, confidence: f32, age_days: i64) -> Submission {
+        Submission {
+            id: id.to_string(),
+            content: format!("Finding {}", id),
+            confidence,
+            provenance: vec!["PubMed".to_string(), "bioRxiv".to_string()],
+            quality_score: 0.9,
+            metadata: HashMap::new(),
+            version: 1,
+            created_at: Some((Utc::now() - Duration::days(age_days)).to_rfc3339()),
+            publication_date: None,
+        }
+    }
+
+    #[test]
+    fn test_newer_evidence_preferred_on_tie() {
+        let submissions = vec![
+            create_test_submission("old", 0.85, 400),
+            create_test_submission("new", 0.85, 2),
+        ];
+
+        let ranked = rank_evidence(&submissions, &EvidenceWeights::default(), Utc::now());
+        assert_eq!(ranked[0].id, "new");
+        assert_eq!(ranked[1].id, "old");
+    }
+
+    #[test]
+    fn test_confidence_dominates_by_default() {
+        let submissions = vec![
+            create_test_submission("recent-weak", 0.75, 1),
+            create_test_submission("old-strong", 0.95, 400),
+        ];
+
+        let ranked = rank_evidence(&submissions, &EvidenceWeights::default(), Utc::now());
+        assert_eq!(ranked[0].id, "old-strong");
+    }
+
+    #[test]
+    fn test_recency_weight_is_configurable() {
+        let submissions = vec![
+            create_test_submission("recent-weak", 0.75, 1),
+            create_test_submission("old-strong", 0.95, 400),
+        ];
+        let weights = EvidenceWeights {
+            confidence: 1.0,
+            recency: 1.0,
+            half_life_days: 30.0,
+        };
+
+        let ranked = rank_evidence(&submissions, &weights, Utc::now());
+        assert_eq!(ranked[0].id, "recent-weak");
+    }
+}
@@ -2,5 +2,8 @@
 pub mod rd;
 pub mod sampler;
 
-pub use rd::{RDPoint, RDCurve, RDOptimizer};
-pub use sampler::{QuantumSampler, SamplingResult};
+pub use rd::{ObjectiveWeights, RDPoint, RDCurve, RDOptimizer};
+pub use sampler::{
+    validate_distribution, validate_distribution_within, NormalizationMode, QuantumSampler, SamplingResult,
+    DEFAULT_SUM_TOLERANCE,
+};
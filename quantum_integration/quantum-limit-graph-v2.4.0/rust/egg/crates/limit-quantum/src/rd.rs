@@ -1,5 +1,6 @@
 // crates/limit-quantum/src/rd.rs
 use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
 
 /// Rate-Distortion (RD) point for quantum-inspired retrieval
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,6 +9,9 @@ pub struct RDPoint {
     pub distortion: f32,
     pub batch_size: usize,
     pub backend: String,
+    /// Nodes plus edges of the graph the point was measured on, if known
+    #[serde(default)]
+    pub graph_size: Option<usize>,
 }
 
 impl RDPoint {
@@ -17,8 +21,14 @@ impl RDPoint {
             distortion,
             batch_size,
             backend,
+            graph_size: None,
         }
     }
+
+    pub fn with_graph_size(mut self, graph_size: usize) -> Self {
+        self.graph_size = Some(graph_size);
+        self
+    }
 }
 
 /// RD curve for optimization
@@ -41,18 +51,24 @@ impl RDCurve {
     }
 
     pub fn compute_optimal(&mut self) {
+        // Find point with best rate-distortion tradeoff
+        self.compute_optimal_with(|p| p.rate / (p.distortion + 1e-6));
+    }
+
+    /// Select the point maximizing an arbitrary scorer, e.g. a Lagrangian
+    /// `rate - lambda * distortion`
+    pub fn compute_optimal_with(&mut self, scorer: impl Fn(&RDPoint) -> f32) {
         if self.points.is_empty() {
             return;
         }
 
-        // Find point with best rate-distortion tradeoff
         let optimal = self
             .points
             .iter()
-            .min_by(|a, b| {
-                let score_a = a.rate / (a.distortion + 1e-6);
-                let score_b = b.rate / (b.distortion + 1e-6);
-                score_a.partial_cmp(&score_b).unwrap()
+            .max_by(|a, b| {
+                scorer(a)
+                    .partial_cmp(&scorer(b))
+                    .unwrap_or(std::cmp::Ordering::Equal)
             })
             .cloned();
 
@@ -62,6 +78,26 @@ impl RDCurve {
     pub fn get_optimal(&self) -> Option<&RDPoint> {
         self.optimal_point.as_ref()
     }
+
+    /// Copy of the curve with each point's rate divided by its graph size,
+    /// so curves measured on differently-sized graphs can be compared
+    ///
+    /// Points without a (non-zero) graph size keep their raw rate. The
+    /// optimal point is carried over normalized, not recomputed.
+    pub fn normalize_by_size(&self) -> RDCurve {
+        let normalize = |p: &RDPoint| match p.graph_size {
+            Some(size) if size > 0 => RDPoint {
+                rate: p.rate / size as f32,
+                ..p.clone()
+            },
+            _ => p.clone(),
+        };
+
+        RDCurve {
+            points: self.points.iter().map(normalize).collect(),
+            optimal_point: self.optimal_point.as_ref().map(normalize),
+        }
+    }
 }
 
 impl Default for RDCurve {
@@ -70,6 +106,34 @@ impl Default for RDCurve {
     }
 }
 
+/// Weights for `RDOptimizer::optimize_multi_objective`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectiveWeights {
+    /// Reward per unit of rate
+    pub rate: f32,
+    /// Penalty per unit of distortion
+    pub distortion: f32,
+    /// Penalty per unit of batch size, relative to the largest batch across all curves
+    pub batch_size: f32,
+    /// Penalty per unit of backend cost
+    pub backend: f32,
+    /// Cost of running on each backend; unlisted backends cost nothing
+    pub backend_costs: HashMap<String, f32>,
+}
+
+impl Default for ObjectiveWeights {
+    fn default() -> Self {
+        Self {
+            rate: 1.0,
+            distortion: 1.0,
+            batch_size: 0.0,
+            backend: 0.0,
+            backend_costs: HashMap::new(),
+        }
+    }
+}
+
 /// RD optimizer for quantum-inspired retrieval
 pub struct RDOptimizer {
     pub curves: Vec<RDCurve>,
@@ -89,6 +153,45 @@ impl RDOptimizer {
             curve.compute_optimal();
         }
     }
+
+    /// Best point of every non-empty curve, as (curve index, point), under
+    ///
+    /// `rate·w_rate − distortion·w_distortion − (batch / max batch)·w_batch − backend cost·w_backend`
+    ///
+    /// Curves are left untouched; ties keep the earliest point.
+    pub fn optimize_multi_objective(&self, weights: &ObjectiveWeights) -> Vec<(usize, RDPoint)> {
+        let max_batch = self
+            .curves
+            .iter()
+            .flat_map(|c| &c.points)
+            .map(|p| p.batch_size)
+            .max()
+            .unwrap_or(0)
+            .max(1) as f32;
+
+        let score = |p: &RDPoint| {
+            let backend_cost = weights.backend_costs.get(&p.backend).copied().unwrap_or(0.0);
+            weights.rate * p.rate
+                - weights.distortion * p.distortion
+                - weights.batch_size * p.batch_size as f32 / max_batch
+                - weights.backend * backend_cost
+        };
+
+        self.curves
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, curve)| {
+                curve
+                    .points
+                    .iter()
+                    .fold(None, |best: Option<&RDPoint>, p| match best {
+                        Some(b) if score(b) >= score(p) => Some(b),
+                        _ => Some(p),
+                    })
+                    .map(|p| (idx, p.clone()))
+            })
+            .collect()
+    }
 }
 
 impl Default for RDOptimizer {
@@ -1,11 +1,43 @@
 // crates/limit-quantum/src/sampler.rs
+use anyhow::{bail, Result};
 use serde::{Serialize, Deserialize};
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+
+/// How far a distribution's sum may stray from 1.0 by default in strict sampling
+pub const DEFAULT_SUM_TOLERANCE: f32 = 1e-3;
+
+/// Check that `probabilities` is a usable distribution: non-empty, every
+/// entry finite and non-negative, summing to 1.0 within `DEFAULT_SUM_TOLERANCE`
+pub fn validate_distribution(probabilities: &[f32]) -> Result<()> {
+    validate_distribution_within(probabilities, DEFAULT_SUM_TOLERANCE)
+}
+
+/// `validate_distribution` with an explicit tolerance on the sum
+pub fn validate_distribution_within(probabilities: &[f32], tolerance: f32) -> Result<()> {
+    if probabilities.is_empty() {
+        bail!("Empty probability distribution");
+    }
+    if let Some(idx) = probabilities.iter().position(|p| !p.is_finite() || *p < 0.0) {
+        bail!("Invalid probability {} at index {}", probabilities[idx], idx);
+    }
+    let sum: f32 = probabilities.iter().sum();
+    if (sum - 1.0).abs() > tolerance {
+        bail!("Probabilities sum to {}, not 1.0 (tolerance {})", sum, tolerance);
+    }
+    Ok(())
+}
 
 /// Quantum-inspired sampler for graph traversal
 pub struct QuantumSampler {
     pub temperature: f32,
     pub num_samples: usize,
+    /// When set, every call to `sample` restarts from this seed so identical
+    /// inputs produce identical samples
+    pub seed: Option<u64>,
+    /// When set, `try_sample` rejects distributions failing
+    /// `validate_distribution_within` this tolerance
+    pub strict_tolerance: Option<f32>,
 }
 
 impl QuantumSampler {
@@ -13,12 +45,39 @@ impl QuantumSampler {
         Self {
             temperature,
             num_samples,
+            seed: None,
+            strict_tolerance: None,
         }
     }
 
+    /// Make sampling reproducible with a fixed seed
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Validate distributions passed to `try_sample`, allowing their sum to
+    /// differ from 1.0 by up to `tolerance`
+    pub fn with_strict(mut self, tolerance: f32) -> Self {
+        self.strict_tolerance = Some(tolerance);
+        self
+    }
+
+    /// `sample`, but in strict mode an invalid distribution is an error
+    /// rather than silently skewed or missing samples
+    pub fn try_sample(&self, probabilities: &[f32]) -> Result<Vec<usize>> {
+        if let Some(tolerance) = self.strict_tolerance {
+            validate_distribution_within(probabilities, tolerance)?;
+        }
+        Ok(self.sample(probabilities))
+    }
+
     /// Sample from probability distribution using quantum-inspired approach
     pub fn sample(&self, probabilities: &[f32]) -> Vec<usize> {
-        let mut rng = rand::thread_rng();
+        let mut rng: Box<dyn RngCore> = match self.seed {
+            Some(seed) => Box::new(StdRng::seed_from_u64(seed)),
+            None => Box::new(rand::thread_rng()),
+        };
         let mut samples = Vec::new();
 
         for _ in 0..self.num_samples {
@@ -38,17 +97,28 @@ impl QuantumSampler {
     }
 
     /// Apply quantum-inspired annealing to probabilities
-    pub fn anneal(&self, probabilities: &[f32]) -> Vec<f32> {
+    ///
+    /// Errors on NaN or infinite inputs, which would otherwise turn the whole
+    /// distribution into NaN.
+    pub fn anneal(&self, probabilities: &[f32]) -> Result<Vec<f32>> {
+        if let Some(idx) = probabilities.iter().position(|p| !p.is_finite()) {
+            bail!("Non-finite probability {} at index {}", probabilities[idx], idx);
+        }
+
         let sum: f32 = probabilities.iter().map(|&p| (p / self.temperature).exp()).sum();
-        probabilities
+        Ok(probabilities
             .iter()
             .map(|&p| (p / self.temperature).exp() / sum)
-            .collect()
+            .collect())
     }
 
     /// Quantum walk step
-    pub fn quantum_walk_step(&self, current_state: &[f32], transition_matrix: &[Vec<f32>]) -> Vec<f32> {
+    ///
+    /// Errors unless `transition_matrix` is square with one row per entry of
+    /// `current_state`.
+    pub fn quantum_walk_step(&self, current_state: &[f32], transition_matrix: &[Vec<f32>]) -> Result<Vec<f32>> {
         let n = current_state.len();
+        check_square(transition_matrix, n)?;
         let mut next_state = vec![0.0; n];
 
         for i in 0..n {
@@ -63,7 +133,100 @@ impl QuantumSampler {
             next_state.iter_mut().for_each(|x| *x /= sum);
         }
 
-        next_state
+        Ok(next_state)
+    }
+
+    /// Walk `steps` steps from node `start` over a weighted adjacency matrix
+    /// (`weights[i][j]` is the confidence of edge i -> j, 0 for no edge)
+    ///
+    /// Each node's outgoing confidences are turned into a transition
+    /// distribution with `mode`; nodes without outgoing edges stay in place.
+    /// Errors when `weights` is not square or `mode` cannot normalize a row.
+    pub fn quantum_walk(
+        &self,
+        start: usize,
+        weights: &[Vec<f32>],
+        steps: usize,
+        mode: NormalizationMode,
+    ) -> Result<Vec<f32>> {
+        let n = weights.len();
+        check_square(weights, n)?;
+        let transition_matrix: Vec<Vec<f32>> = weights
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let mut transitions = mode.normalize(row)?;
+                if transitions.iter().all(|&p| p == 0.0) {
+                    transitions[i] = 1.0;
+                }
+                Ok(transitions)
+            })
+            .collect::<Result<_>>()?;
+
+        let mut state = vec![0.0; n];
+        if start < n {
+            state[start] = 1.0;
+        }
+        for _ in 0..steps {
+            state = self.quantum_walk_step(&state, &transition_matrix)?;
+        }
+        Ok(state)
+    }
+}
+
+/// Error unless `matrix` has `n` rows of `n` entries each
+fn check_square(matrix: &[Vec<f32>], n: usize) -> Result<()> {
+    if matrix.len() != n {
+        bail!("Transition matrix has {} rows, expected {}", matrix.len(), n);
+    }
+    if let Some(idx) = matrix.iter().position(|row| row.len() != n) {
+        bail!("Transition matrix row {} has {} entries, expected {}", idx, matrix[idx].len(), n);
+    }
+    Ok(())
+}
+
+/// How neighbor confidences become transition probabilities in `quantum_walk`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum NormalizationMode {
+    /// Proportional to confidence
+    Linear,
+    /// Softmax over neighbors; low temperatures sharpen toward the most
+    /// confident neighbor, high temperatures flatten toward uniform
+    Softmax(f32),
+}
+
+impl NormalizationMode {
+    /// Normalize positive weights into a distribution; non-positive entries
+    /// (absent edges) get probability 0
+    ///
+    /// Errors on NaN or infinite weights and on a softmax temperature that is
+    /// not finite and positive, which would otherwise yield NaN probabilities.
+    pub fn normalize(&self, weights: &[f32]) -> Result<Vec<f32>> {
+        if let Some(idx) = weights.iter().position(|w| !w.is_finite()) {
+            bail!("Non-finite weight {} at index {}", weights[idx], idx);
+        }
+        if let NormalizationMode::Softmax(temperature) = *self {
+            if !(temperature.is_finite() && temperature > 0.0) {
+                bail!("Softmax temperature must be finite and positive, got {}", temperature);
+            }
+        }
+        let scaled: Vec<f32> = match *self {
+            NormalizationMode::Linear => weights.iter().map(|&w| w.max(0.0)).collect(),
+            NormalizationMode::Softmax(temperature) => {
+                let max = weights.iter().copied().filter(|&w| w > 0.0).fold(f32::MIN, f32::max);
+                weights
+                    .iter()
+                    .map(|&w| if w > 0.0 { ((w - max) / temperature).exp() } else { 0.0 })
+                    .collect()
+            }
+        };
+
+        let sum: f32 = scaled.iter().sum();
+        if sum > 0.0 {
+            Ok(scaled.iter().map(|&w| w / sum).collect())
+        } else {
+            Ok(scaled)
+        }
     }
 }
 
@@ -85,10 +248,11 @@ impl SamplingResult {
         }
     }
 
+    /// Shannon entropy in bits; NaN and infinite entries are skipped
     fn compute_entropy(probabilities: &[f32]) -> f32 {
         probabilities
             .iter()
-            .filter(|&&p| p > 0.0)
+            .filter(|&&p| p.is_finite() && p > 0.0)
             .map(|&p| -p * p.log2())
             .sum()
     }
@@ -1,7 +1,7 @@
 // tests/governance_tests.rs
 #[cfg(test)]
 mod tests {
-    use limit_hub::governance::{GovernanceRules, Submission};
+    use limit_hub::governance::{CompositeWeights, GovernanceRules, Submission};
     use std::collections::HashMap;
 
     fn create_test_submission(confidence: f32, provenance_count: usize, quality: f32) -> Submission {
@@ -14,6 +14,9 @@ mod tests {
                 .collect(),
             quality_score: quality,
             metadata: HashMap::new(),
+            version: 0,
+            created_at: None,
+            publication_date: None,
         }
     }
 
@@ -68,6 +71,12 @@ mod tests {
             allowed_sources: vec!["PubMed".to_string()],
             quality_threshold: 0.95,
             review_required: false,
+            composite_weights: CompositeWeights::default(),
+            composite_threshold: None,
+            source_trust: HashMap::new(),
+            check_provenance_format: false,
+            max_age_days: None,
+            warn_undated: false,
         };
         
         let submission = create_test_submission(0.85, 3, 0.9);
@@ -87,4 +96,90 @@ mod tests {
         assert!(result.errors.len() >= 2);
         assert!(!result.warnings.is_empty());
     }
+
+    #[test]
+    fn test_composite_gate() {
+        let mut rules = GovernanceRules::default_rules();
+        let submission = create_test_submission(0.75, 2, 0.85);
+        assert!(rules.validate_submission(&submission).valid);
+
+        rules.composite_weights = CompositeWeights {
+            confidence: 1.0,
+            quality: 1.0,
+            sources: 0.0,
+        };
+        rules.composite_threshold = Some(0.85);
+        assert!((rules.composite_score(&submission) - 0.8).abs() < 1e-6);
+
+        let result = rules.validate_submission(&submission);
+        assert!(!result.valid);
+        assert!(result.errors.iter().any(|e| e.contains("Composite score 0.800")));
+    }
+
+    #[test]
+    fn test_low_trust_source_fails_validation() {
+        let mut rules = GovernanceRules::default_rules();
+        rules.source_trust.insert("Preprint".to_string(), 0.5);
+
+        let mut submission = create_test_submission(0.95, 2, 0.9);
+        submission.provenance = vec!["Preprint:001".to_string(), "Preprint:002".to_string()];
+
+        assert!((rules.effective_confidence(&submission) - 0.475).abs() < 1e-6);
+        let result = rules.validate_submission(&submission);
+        assert!(!result.valid);
+        assert!(result.errors.iter().any(|e| e.contains("Effective confidence")));
+
+        submission.provenance.push("PubMed:12345".to_string());
+        assert!(rules.validate_submission(&submission).valid);
+    }
+
+    #[test]
+    fn test_provenance_format_warnings() {
+        let mut rules = GovernanceRules::default_rules();
+        let mut submission = create_test_submission(0.85, 0, 0.9);
+        submission.provenance = vec![
+            "not-a-real-citation".to_string(),
+            "10.1038/s41586-020-2012-7".to_string(),
+            "PMID:32015507".to_string(),
+            "https://www.biorxiv.org/content/10.1101/2020.01.22.914952v2".to_string(),
+        ];
+
+        let warnings = rules.validate_provenance_format(&submission);
+        assert_eq!(
+            warnings,
+            vec!["Provenance 'not-a-real-citation' is not a recognised DOI, PubMed or bioRxiv identifier"]
+        );
+
+        // Only reported by validate_submission when enabled
+        let format_warning = |w: &String| w.contains("not a recognised");
+        assert!(!rules.validate_submission(&submission).warnings.iter().any(format_warning));
+        rules.check_provenance_format = true;
+        assert!(rules.validate_submission(&submission).warnings.iter().any(format_warning));
+    }
+
+    #[test]
+    fn test_max_age_rejects_stale_submissions() {
+        let mut rules = GovernanceRules::default_rules();
+        rules.max_age_days = Some(365);
+
+        // Published well over two years ago
+        let mut submission = create_test_submission(0.85, 2, 0.9);
+        submission.publication_date = Some("2020-03-11".to_string());
+        let result = rules.validate_submission(&submission);
+        assert!(!result.valid);
+        assert!(result.errors.iter().any(|e| e.contains("beyond maximum age 365")));
+
+        submission.publication_date = Some("March 2020".to_string());
+        assert!(!rules.validate_submission(&submission).valid);
+
+        // Dateless submissions pass, with a warning only when configured
+        submission.publication_date = None;
+        let no_date_warning = |w: &String| w.contains("No publication date");
+        assert!(rules.validate_submission(&submission).valid);
+        assert!(!rules.validate_submission(&submission).warnings.iter().any(no_date_warning));
+        rules.warn_undated = true;
+        let result = rules.validate_submission(&submission);
+        assert!(result.valid);
+        assert!(result.warnings.iter().any(no_date_warning));
+    }
 }
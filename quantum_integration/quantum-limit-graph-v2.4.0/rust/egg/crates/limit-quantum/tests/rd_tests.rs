@@ -1,7 +1,7 @@
 // tests/rd_tests.rs
 #[cfg(test)]
 mod tests {
-    use limit_quantum::rd::{RDPoint, RDCurve, RDOptimizer};
+    use limit_quantum::rd::{ObjectiveWeights, RDPoint, RDCurve, RDOptimizer};
 
     #[test]
     fn test_rd_point_creation() {
@@ -65,4 +65,76 @@ mod tests {
         assert_eq!(optimal.rate, 0.75);
         assert_eq!(optimal.distortion, 0.25);
     }
+
+    #[test]
+    fn test_multi_objective_distortion_weight() {
+        let mut fast = RDCurve::new();
+        fast.add_point(RDPoint::new(0.9, 0.25, 32, "sim".to_string()));
+        fast.add_point(RDPoint::new(0.6, 0.05, 32, "sim".to_string()));
+        let mut qpu = RDCurve::new();
+        qpu.add_point(RDPoint::new(0.7, 0.2, 64, "qpu".to_string()));
+
+        let mut optimizer = RDOptimizer::new();
+        optimizer.add_curve(fast);
+        optimizer.add_curve(RDCurve::new());
+        optimizer.add_curve(qpu);
+
+        let mut weights = ObjectiveWeights::default();
+        let best = optimizer.optimize_multi_objective(&weights);
+        assert_eq!(best.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(best[0].1.rate, 0.9);
+
+        // Penalizing distortion more heavily flips the winner within the first curve
+        weights.distortion = 2.0;
+        let best = optimizer.optimize_multi_objective(&weights);
+        assert_eq!(best[0].1.rate, 0.6);
+    }
+
+    #[test]
+    fn test_normalize_by_size_changes_best_curve() {
+        let mut large = RDCurve::new();
+        large.add_point(RDPoint::new(0.9, 0.2, 32, "sim".to_string()).with_graph_size(1000));
+        let mut small = RDCurve::new();
+        small.add_point(RDPoint::new(0.5, 0.2, 32, "sim".to_string()).with_graph_size(100));
+
+        let best_curve = |curves: &[RDCurve]| {
+            let mut optimizer = RDOptimizer::new();
+            for curve in curves {
+                optimizer.add_curve(curve.clone());
+            }
+            optimizer
+                .optimize_multi_objective(&ObjectiveWeights::default())
+                .into_iter()
+                .max_by(|a, b| (a.1.rate - a.1.distortion).total_cmp(&(b.1.rate - b.1.distortion)))
+                .map(|(idx, _)| idx)
+        };
+
+        let raw = [large.clone(), small.clone()];
+        let normalized = [large.normalize_by_size(), small.normalize_by_size()];
+        assert_eq!(best_curve(&raw), Some(0));
+        assert_eq!(best_curve(&normalized), Some(1));
+        assert!((normalized[1].points[0].rate - 0.005).abs() < 1e-6);
+        assert_eq!(normalized[1].points[0].graph_size, Some(100));
+
+        // Points of unknown size keep their raw rate
+        let mut unsized_curve = RDCurve::new();
+        unsized_curve.add_point(RDPoint::new(0.7, 0.1, 8, "sim".to_string()));
+        assert_eq!(unsized_curve.normalize_by_size().points[0].rate, 0.7);
+    }
+
+    #[test]
+    fn test_custom_scorer() {
+        let mut curve = RDCurve::new();
+        curve.add_point(RDPoint::new(0.9, 0.15, 64, "sim".to_string()));
+        curve.add_point(RDPoint::new(0.5, 0.05, 16, "sim".to_string()));
+
+        // Default ratio favours the low-distortion point (10.0 vs 6.0)
+        curve.compute_optimal();
+        assert_eq!(curve.get_optimal().unwrap().rate, 0.5);
+
+        // Lagrangian rate - lambda * distortion favours the high-rate point
+        let lambda = 1.0;
+        curve.compute_optimal_with(|p| p.rate - lambda * p.distortion);
+        assert_eq!(curve.get_optimal().unwrap().rate, 0.9);
+    }
 }
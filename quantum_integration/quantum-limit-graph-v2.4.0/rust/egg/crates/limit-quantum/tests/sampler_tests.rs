@@ -0,0 +1,95 @@
+// tests/sampler_tests.rs
+#[cfg(test)]
+mod tests {
+    use limit_quantum::sampler::{validate_distribution, NormalizationMode, QuantumSampler, SamplingResult};
+
+    #[test]
+    fn test_seeded_sampling_is_reproducible() {
+        let sampler = QuantumSampler::new(0.5, 50).with_seed(7);
+        let probabilities = vec![0.3, 0.5, 0.2];
+
+        assert_eq!(sampler.sample(&probabilities), sampler.sample(&probabilities));
+    }
+
+    #[test]
+    fn test_validate_distribution_rejects_negative_entry() {
+        let err = validate_distribution(&[0.6, -0.1, 0.5]).unwrap_err();
+        assert!(err.to_string().contains("index 1"));
+
+        let sampler = QuantumSampler::new(0.5, 10).with_strict(1e-3);
+        assert!(sampler.try_sample(&[0.6, -0.1, 0.5]).is_err());
+        // Without strict mode the distribution is sampled as given
+        assert!(QuantumSampler::new(0.5, 10).try_sample(&[0.6, -0.1, 0.5]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_distribution_rejects_empty() {
+        assert!(validate_distribution(&[]).is_err());
+        assert!(QuantumSampler::new(0.5, 10).with_strict(1e-3).try_sample(&[]).is_err());
+    }
+
+    #[test]
+    fn test_validate_distribution_accepts_valid() {
+        assert!(validate_distribution(&[0.3, 0.5, 0.2]).is_ok());
+        assert!(validate_distribution(&[0.3, 0.5, 0.3]).is_err());
+
+        let sampler = QuantumSampler::new(0.5, 20).with_seed(7).with_strict(1e-3);
+        let samples = sampler.try_sample(&[0.3, 0.5, 0.2]).unwrap();
+        assert_eq!(samples.len(), 20);
+        assert!(samples.iter().all(|&idx| idx < 3));
+    }
+
+    #[test]
+    fn test_anneal_rejects_non_finite() {
+        let sampler = QuantumSampler::new(0.5, 10);
+
+        let err = sampler.anneal(&[0.3, f32::NAN, 0.2]).unwrap_err();
+        assert!(err.to_string().contains("index 1"));
+        assert!(sampler.anneal(&[0.3, f32::INFINITY]).is_err());
+
+        let annealed = sampler.anneal(&[0.3, 0.5, 0.2]).unwrap();
+        assert!((annealed.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_entropy_skips_non_finite() {
+        let result = SamplingResult::new(vec![], vec![0.5, f32::NAN, 0.5, f32::INFINITY]);
+
+        assert!(result.entropy.is_finite());
+        assert!((result.entropy - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_softmax_walk_concentrates_on_confident_neighbor() {
+        let sampler = QuantumSampler::new(0.5, 100);
+        let weights = vec![
+            vec![0.0, 0.9, 0.6, 0.5],
+            vec![0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0],
+        ];
+
+        let linear = sampler.quantum_walk(0, &weights, 1, NormalizationMode::Linear).unwrap();
+        let sharp = sampler.quantum_walk(0, &weights, 1, NormalizationMode::Softmax(0.05)).unwrap();
+
+        assert!((linear[1] - 0.45).abs() < 1e-6);
+        assert!(sharp[1] > 0.95);
+        assert!((sharp.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_quantum_walk_rejects_bad_temperature_and_shape() {
+        let sampler = QuantumSampler::new(0.5, 100);
+        let weights = vec![vec![0.0, 0.9], vec![0.4, 0.0]];
+
+        for temperature in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert!(sampler.quantum_walk(0, &weights, 1, NormalizationMode::Softmax(temperature)).is_err());
+        }
+        assert!(NormalizationMode::Linear.normalize(&[0.5, f32::NAN]).is_err());
+
+        let ragged = vec![vec![0.0, 0.9], vec![0.4]];
+        assert!(sampler.quantum_walk(0, &ragged, 1, NormalizationMode::Linear).is_err());
+        assert!(sampler.quantum_walk_step(&[1.0, 0.0], &[vec![0.0, 1.0]]).is_err());
+        assert!(sampler.quantum_walk_step(&[1.0, 0.0], &weights).is_ok());
+    }
+}
@@ -1,23 +1,38 @@
 // crates/limit-reflection/src/api.rs
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
-    response::Json,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Json, Response},
     routing::{get, post},
     Router,
 };
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
 use tokio::sync::RwLock;
+use tower_http::limit::RequestBodyLimitLayer;
 
-use crate::engine::{ReflectionEngine, ReflectionResult};
+use crate::engine::{DeepReflectionResult, ReflectionEngine, ReflectionError, ReflectionResult};
 use crate::govern::{ReflectionGovernance, QualityReport};
-use crate::model::MetaCognitiveInsights;
+use crate::model::{MetaCognitiveInsights, ReflectionModel, StepType, SuggestionType};
+
+/// Default cap on request bodies; larger requests are rejected with 413
+pub const DEFAULT_MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Default number of quality snapshots kept for `GET /quality-trend`
+pub const DEFAULT_QUALITY_TREND_CAPACITY: usize = 100;
 
 /// API state
 pub struct ReflectionApiState {
     pub engine: ReflectionEngine,
     pub governance: ReflectionGovernance,
+    /// Request body size limit applied by the router
+    pub max_body_bytes: usize,
+    /// Quality of the whole model after each reflection served, oldest first
+    pub quality_trend: Mutex<VecDeque<QualityReport>>,
+    /// Snapshots kept before the oldest is dropped
+    pub quality_trend_capacity: usize,
 }
 
 impl ReflectionApiState {
@@ -25,13 +40,47 @@ impl ReflectionApiState {
         Self {
             engine: ReflectionEngine::new(3),
             governance: ReflectionGovernance::default_rules(),
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            quality_trend: Mutex::new(VecDeque::new()),
+            quality_trend_capacity: DEFAULT_QUALITY_TREND_CAPACITY,
         }
     }
+
+    pub fn with_max_body_bytes(mut self, max_body_bytes: usize) -> Self {
+        self.max_body_bytes = max_body_bytes;
+        self
+    }
+
+    pub fn with_quality_trend_capacity(mut self, capacity: usize) -> Self {
+        self.quality_trend_capacity = capacity;
+        self
+    }
+
+    /// Snapshot the model's current quality onto the trend
+    fn record_quality(&self) -> Result<(), ReflectionError> {
+        let report = self.governance.check_quality(&*self.engine.read_model()?);
+        let mut trend = self.quality_trend()?;
+        trend.push_back(report);
+        while trend.len() > self.quality_trend_capacity {
+            trend.pop_front();
+        }
+        Ok(())
+    }
+
+    fn quality_trend(&self) -> Result<MutexGuard<'_, VecDeque<QualityReport>>, ReflectionError> {
+        self.quality_trend.lock().map_err(|_| ReflectionError::LockPoisoned { lock: "quality trend" })
+    }
 }
 
 /// Create reflection API router
 pub fn create_router() -> Router {
-    let state = Arc::new(RwLock::new(ReflectionApiState::new()));
+    create_router_with_state(ReflectionApiState::new())
+}
+
+/// Create reflection API router around an existing engine and governance
+pub fn create_router_with_state(state: ReflectionApiState) -> Router {
+    let body_limit = RequestBodyLimitLayer::new(state.max_body_bytes);
+    let state = Arc::new(RwLock::new(state));
 
     Router::new()
         .route("/health", get(health_check))
@@ -40,6 +89,12 @@ pub fn create_router() -> Router {
         .route("/insights", get(get_insights))
         .route("/suggestions", get(get_suggestions))
         .route("/quality", get(check_quality))
+        .route("/quality-trend", get(get_quality_trend))
+        .route("/errors", get(get_errors))
+        .route("/step-types", get(get_step_types))
+        .route("/rpc", post(json_rpc))
+        .route("/metrics", get(metrics))
+        .layer(body_limit)
         .with_state(state)
 }
 
@@ -51,22 +106,55 @@ async fn health_check() -> Json<HealthResponse> {
     })
 }
 
+/// Reflect on a query, rendered as JSON (default), plain text or markdown
+/// per `?format=`, falling back to the `Accept` header
 async fn reflect_on_query(
     State(state): State<Arc<RwLock<ReflectionApiState>>>,
+    Query(params): Query<FormatParams>,
+    headers: HeaderMap,
     Json(request): Json<ReflectRequest>,
-) -> Result<Json<ReflectResponse>, StatusCode> {
+) -> Result<Response, StatusCode> {
     let state = state.read().await;
 
     let result = state
         .engine
         .reflect_on_query(&request.query)
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(|e| error_status(&e))?;
+    state.record_quality().map_err(|e| error_status(&e))?;
 
-    Ok(Json(ReflectResponse {
+    let format = params.format.unwrap_or_else(|| OutputFormat::from_accept(&headers));
+    Ok(match format {
+        OutputFormat::Json => Json(reflect_response(result, &state.governance)).into_response(),
+        OutputFormat::Text => (
+            [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+            state.engine.explain(&result),
+        )
+            .into_response(),
+        OutputFormat::Markdown => (
+            [(header::CONTENT_TYPE, "text/markdown; charset=utf-8")],
+            state.engine.explain_markdown(&result),
+        )
+            .into_response(),
+    })
+}
+
+/// Summarize a reflection, judging it against governance on a model holding
+/// only this reflection's steps
+fn reflect_response(result: ReflectionResult, governance: &ReflectionGovernance) -> ReflectResponse {
+    let mut snapshot = ReflectionModel::new();
+    for step in &result.steps {
+        snapshot.add_step(step.clone());
+    }
+    let validation = governance.validate_reflection(&snapshot);
+    let quality = governance.check_quality(&snapshot);
+
+    ReflectResponse {
         steps_count: result.steps.len(),
         final_confidence: result.final_confidence,
         insights: result.insights,
-    }))
+        meets_standards: validation.valid && quality.meets_standards,
+        governance_warnings: validation.errors.into_iter().chain(validation.warnings).collect(),
+    }
 }
 
 async fn deep_reflect(
@@ -78,13 +166,28 @@ async fn deep_reflect(
     let result = state
         .engine
         .deep_reflect(&request.query)
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(|e| error_status(&e))?;
+    state.record_quality().map_err(|e| error_status(&e))?;
 
-    Ok(Json(DeepReflectResponse {
+    Ok(Json(deep_reflect_response(result)))
+}
+
+fn deep_reflect_response(result: DeepReflectionResult) -> DeepReflectResponse {
+    DeepReflectResponse {
         layers_count: result.layers.len(),
         final_depth: result.final_depth,
         final_confidence: result.layers.last().map(|l| l.final_confidence).unwrap_or(0.0),
-    }))
+    }
+}
+
+/// Empty queries are rejected with 400 and exhausted step budgets surface as
+/// 429; anything else is a server error
+pub fn error_status(error: &ReflectionError) -> StatusCode {
+    match error {
+        ReflectionError::EmptyQuery => StatusCode::BAD_REQUEST,
+        ReflectionError::BudgetExhausted(_) => StatusCode::TOO_MANY_REQUESTS,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
 }
 
 async fn get_insights(
@@ -94,26 +197,200 @@ async fn get_insights(
     Json(state.engine.get_insights())
 }
 
+/// Engine statistics in the Prometheus text exposition format
+async fn metrics(State(state): State<Arc<RwLock<ReflectionApiState>>>) -> impl IntoResponse {
+    let state = state.read().await;
+    let insights = state.engine.get_insights();
+
+    let metrics = [
+        (
+            "reflection_total_steps",
+            "counter",
+            "Reasoning steps recorded",
+            insights.total_steps as f64,
+        ),
+        (
+            "reflection_average_confidence",
+            "gauge",
+            "Mean confidence over all steps",
+            insights.average_confidence as f64,
+        ),
+        (
+            "reflection_total_errors",
+            "counter",
+            "Errors recorded",
+            insights.total_errors as f64,
+        ),
+        (
+            "reflection_suggestions_total",
+            "gauge",
+            "Current improvement suggestions",
+            insights.suggestions_count as f64,
+        ),
+    ];
+
+    let body: String = metrics
+        .iter()
+        .map(|(name, kind, help, value)| {
+            format!("# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}\n")
+        })
+        .collect();
+
+    ([(header::CONTENT_TYPE, "text/plain; version=0.0.4")], body)
+}
+
 async fn get_suggestions(
     State(state): State<Arc<RwLock<ReflectionApiState>>>,
-) -> Json<SuggestionsResponse> {
+    Query(filter): Query<SuggestionsFilter>,
+) -> Result<Json<SuggestionsResponse>, StatusCode> {
     let state = state.read().await;
-    let suggestions = state.engine.get_suggestions();
-    let approved = state.governance.approve_suggestions(&suggestions);
+    let suggestions = approved_suggestions(&state, filter).map_err(|e| error_status(&e))?;
+    Ok(Json(suggestions))
+}
+
+fn approved_suggestions(
+    state: &ReflectionApiState,
+    filter: SuggestionsFilter,
+) -> Result<SuggestionsResponse, ReflectionError> {
+    let suggestions = state.engine.get_suggestions()?;
+    let mut approved = state.governance.approve_suggestions(&suggestions);
+
+    if let Some(suggestion_type) = filter.suggestion_type {
+        approved.retain(|a| a.suggestion.suggestion_type == suggestion_type);
+    }
 
-    Json(SuggestionsResponse {
+    Ok(SuggestionsResponse {
         total: suggestions.len(),
         approved: approved.len(),
         suggestions: approved,
     })
 }
 
+/// JSON-RPC 2.0 endpoint dispatching `reflect`, `deep_reflect`, `insights`
+/// and `suggestions` to the same engine calls as the REST routes
+///
+/// Always answers 200 with a result or error envelope, as the spec requires.
+async fn json_rpc(
+    State(state): State<Arc<RwLock<ReflectionApiState>>>,
+    body: String,
+) -> Json<JsonRpcResponse> {
+    let request: JsonRpcRequest = match serde_json::from_str(&body) {
+        Ok(request) => request,
+        Err(e) => return Json(JsonRpcResponse::error(Value::Null, PARSE_ERROR, e.to_string())),
+    };
+    let id = request.id.clone();
+
+    if request.jsonrpc != "2.0" {
+        return Json(JsonRpcResponse::error(id, INVALID_REQUEST, "jsonrpc must be \"2.0\""));
+    }
+
+    let state = state.read().await;
+    let outcome = match request.method.as_str() {
+        "reflect" => rpc_params::<ReflectRequest>(request.params).and_then(|params| {
+            state
+                .engine
+                .reflect_on_query(&params.query)
+                .map(|result| json!(reflect_response(result, &state.governance)))
+                .map_err(rpc_engine_error)
+        }),
+        "deep_reflect" => rpc_params::<ReflectRequest>(request.params).and_then(|params| {
+            state
+                .engine
+                .deep_reflect(&params.query)
+                .map(|result| json!(deep_reflect_response(result)))
+                .map_err(rpc_engine_error)
+        }),
+        "insights" => Ok(json!(state.engine.get_insights())),
+        "suggestions" => rpc_params::<SuggestionsFilter>(request.params).and_then(|filter| {
+            approved_suggestions(&state, filter)
+                .map(|suggestions| json!(suggestions))
+                .map_err(rpc_engine_error)
+        }),
+        method => Err((METHOD_NOT_FOUND, format!("Method not found: {}", method))),
+    };
+
+    let outcome = outcome.and_then(|result| {
+        if matches!(request.method.as_str(), "reflect" | "deep_reflect") {
+            state.record_quality().map_err(rpc_engine_error)?;
+        }
+        Ok(result)
+    });
+
+    Json(match outcome {
+        Ok(result) => JsonRpcResponse::result(id, result),
+        Err((code, message)) => JsonRpcResponse::error(id, code, message),
+    })
+}
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+/// Implementation-defined server error: reflection budget exhausted
+const BUDGET_EXHAUSTED: i64 = -32000;
+
+/// Decode method params; omitted params decode as an empty object
+fn rpc_params<T: serde::de::DeserializeOwned>(params: Value) -> Result<T, (i64, String)> {
+    let params = if params.is_null() { json!({}) } else { params };
+    serde_json::from_value(params).map_err(|e| (INVALID_PARAMS, e.to_string()))
+}
+
+fn rpc_engine_error(error: ReflectionError) -> (i64, String) {
+    let code = match error {
+        ReflectionError::EmptyQuery => INVALID_PARAMS,
+        ReflectionError::BudgetExhausted(_) => BUDGET_EXHAUSTED,
+        _ => INTERNAL_ERROR,
+    };
+    (code, error.to_string())
+}
+
 async fn check_quality(
     State(state): State<Arc<RwLock<ReflectionApiState>>>,
-) -> Json<QualityReport> {
+) -> Result<Json<QualityReport>, StatusCode> {
+    let state = state.read().await;
+    let model = state.engine.read_model().map_err(|e| error_status(&e))?;
+    Ok(Json(state.governance.check_quality(&model)))
+}
+
+/// Quality snapshots taken after each reflection, oldest first
+async fn get_quality_trend(
+    State(state): State<Arc<RwLock<ReflectionApiState>>>,
+) -> Result<Json<Vec<QualityReport>>, StatusCode> {
+    let state = state.read().await;
+    let trend = state.quality_trend().map_err(|e| error_status(&e))?;
+    Ok(Json(trend.iter().cloned().collect()))
+}
+
+async fn get_errors(
+    State(state): State<Arc<RwLock<ReflectionApiState>>>,
+) -> Result<Json<ErrorsResponse>, StatusCode> {
+    let state = state.read().await;
+    let model = state.engine.read_model().map_err(|e| error_status(&e))?;
+
+    let mut errors: Vec<ErrorPattern> = model
+        .error_patterns
+        .iter()
+        .map(|(error_type, count)| ErrorPattern {
+            error_type: error_type.clone(),
+            count: *count,
+        })
+        .collect();
+    errors.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.error_type.cmp(&b.error_type)));
+
+    Ok(Json(ErrorsResponse {
+        total_errors: errors.iter().map(|e| e.count).sum(),
+        errors,
+    }))
+}
+
+/// How many steps of each type the model's trace holds
+async fn get_step_types(
+    State(state): State<Arc<RwLock<ReflectionApiState>>>,
+) -> Result<Json<HashMap<StepType, usize>>, StatusCode> {
     let state = state.read().await;
-    let model = state.engine.model.read().unwrap();
-    Json(state.governance.check_quality(&*model))
+    let model = state.engine.read_model().map_err(|e| error_status(&e))?;
+    Ok(Json(model.step_type_counts()))
 }
 
 #[derive(Debug, Serialize)]
@@ -128,11 +405,48 @@ struct ReflectRequest {
     query: String,
 }
 
+/// Rendering of a reflection result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum OutputFormat {
+    Json,
+    Text,
+    Markdown,
+}
+
+impl OutputFormat {
+    /// `text/markdown` or `text/plain` in `Accept` select those renderings;
+    /// anything else gets JSON
+    fn from_accept(headers: &HeaderMap) -> Self {
+        let accept = headers
+            .get(header::ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("");
+        if accept.contains("text/markdown") {
+            OutputFormat::Markdown
+        } else if accept.contains("text/plain") {
+            OutputFormat::Text
+        } else {
+            OutputFormat::Json
+        }
+    }
+}
+
+/// Unknown `format` values are rejected by the `Query` extractor with 400
+#[derive(Debug, Deserialize)]
+struct FormatParams {
+    format: Option<OutputFormat>,
+}
+
 #[derive(Debug, Serialize)]
 struct ReflectResponse {
     steps_count: usize,
     final_confidence: f32,
     insights: MetaCognitiveInsights,
+    /// Whether this reflection alone passes governance validation and quality
+    meets_standards: bool,
+    /// Governance errors and warnings for this reflection
+    governance_warnings: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -142,9 +456,78 @@ struct DeepReflectResponse {
     final_confidence: f32,
 }
 
+/// Unknown `type` values are rejected by the `Query` extractor with 400
+#[derive(Debug, Deserialize)]
+struct SuggestionsFilter {
+    #[serde(rename = "type")]
+    suggestion_type: Option<SuggestionType>,
+}
+
 #[derive(Debug, Serialize)]
 struct SuggestionsResponse {
     total: usize,
     approved: usize,
     suggestions: Vec<crate::govern::ApprovedSuggestion>,
 }
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcRequest {
+    jsonrpc: String,
+    method: String,
+    #[serde(default)]
+    params: Value,
+    #[serde(default)]
+    id: Value,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonRpcResponse {
+    jsonrpc: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<JsonRpcError>,
+    id: Value,
+}
+
+impl JsonRpcResponse {
+    fn result(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            result: Some(result),
+            error: None,
+            id,
+        }
+    }
+
+    fn error(id: Value, code: i64, message: impl Into<String>) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            result: None,
+            error: Some(JsonRpcError {
+                code,
+                message: message.into(),
+            }),
+            id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorPattern {
+    error_type: String,
+    count: usize,
+}
+
+/// Recorded error types, most frequent first
+#[derive(Debug, Serialize)]
+struct ErrorsResponse {
+    total_errors: usize,
+    errors: Vec<ErrorPattern>,
+}
@@ -1,14 +1,150 @@
 // crates/limit-reflection/src/engine.rs
+use crate::govern::{ReflectionGovernance, ReflectionRules};
 use crate::model::{ReflectionModel, ReasoningStep, StepType};
 use crate::quantum::QuantumReflector;
-use anyhow::Result;
-use std::sync::{Arc, RwLock};
+use limit_bio_sars::BioGraph;
+use limit_hub::governance::Submission;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// Fixed sampler seed used by deterministic engines
+const DETERMINISTIC_SEED: u64 = 0x5A25_C0F2;
+
+/// Confidence the governance check takes off the conclusion per error and
+/// per warning governance raises against the partial trace
+const GOVERNANCE_ERROR_PENALTY: f32 = 0.2;
+const GOVERNANCE_WARNING_PENALTY: f32 = 0.1;
 
 /// Meta-cognitive reasoning engine
 pub struct ReflectionEngine {
-    pub model: Arc<RwLock<ReflectionModel>>,
+    /// Only written through `update_model` (or the engine's own writers), so
+    /// `counters` always mirror it
+    model: Arc<RwLock<ReflectionModel>>,
     quantum_reflector: QuantumReflector,
     reflection_depth: usize,
+    deterministic: bool,
+    /// Refuse further reflection once this many steps have been taken
+    pub max_total_steps: Option<usize>,
+    steps_taken: AtomicUsize,
+    /// Every step confidence (and so `final_confidence`) is clamped into
+    /// `[confidence_floor, confidence_ceiling]`
+    pub confidence_floor: f32,
+    pub confidence_ceiling: f32,
+    /// Challenge conclusions whose final confidence falls below this with a
+    /// `Critique` step
+    pub critique_threshold: Option<f32>,
+    /// Validate the trace against these rules before concluding, in a
+    /// `Validation` step
+    governance_check: Option<ReflectionGovernance>,
+    /// How query complexity lowers the confidence of the complexity step
+    pub complexity_penalty: ComplexityPenalty,
+    /// Results of `reflect_on_query` keyed by query (deterministic engines only)
+    cache: Option<Mutex<ResultCache>>,
+    cache_hits: AtomicUsize,
+    cache_misses: AtomicUsize,
+    /// The model's insight figures, read by `get_insights` without locking it
+    counters: InsightCounters,
+    /// Notified of every step as it completes
+    observer: Option<Box<dyn ReflectionObserver>>,
+}
+
+/// The model's insight figures, re-derived from it after every write
+///
+/// Refreshed while the model's write lock is still held; readers never lock,
+/// and may see one write's figures mixed with the next's.
+#[derive(Default)]
+struct InsightCounters {
+    total_steps: AtomicUsize,
+    /// `average_confidence` as `f32` bits
+    average_confidence: AtomicU32,
+    total_errors: AtomicUsize,
+    unique_error_types: AtomicUsize,
+    suggestions_count: AtomicUsize,
+}
+
+impl InsightCounters {
+    fn refresh(&self, model: &ReflectionModel) {
+        let insights = model.get_insights();
+        self.total_steps.store(insights.total_steps, Ordering::SeqCst);
+        self.average_confidence
+            .store(insights.average_confidence.to_bits(), Ordering::SeqCst);
+        self.total_errors.store(insights.total_errors, Ordering::SeqCst);
+        self.unique_error_types.store(insights.unique_error_types, Ordering::SeqCst);
+        self.suggestions_count.store(insights.suggestions_count, Ordering::SeqCst);
+    }
+}
+
+/// Read-only handle to an engine's model that stays valid after the engine
+/// is moved (e.g. into a server's state)
+#[derive(Clone)]
+pub struct ModelReader(Arc<RwLock<ReflectionModel>>);
+
+impl ModelReader {
+    /// Read access to the model, failing if a writer panicked
+    pub fn read(&self) -> Result<RwLockReadGuard<'_, ReflectionModel>, ReflectionError> {
+        self.0.read().map_err(|_| ReflectionError::LockPoisoned { lock: "model" })
+    }
+}
+
+/// Hook for embedders to log, stream or collect reasoning steps as the
+/// engine produces them
+///
+/// Called once per completed step, with its confidence already clamped.
+/// Results served from the cache produce no new steps and no callbacks.
+pub trait ReflectionObserver: Send + Sync {
+    fn on_step(&self, step: &ReasoningStep);
+}
+
+/// Shape of the complexity penalty, each rising from 0 at complexity 0 to 1
+/// at complexity 1
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PenaltyCurve {
+    /// Proportional to complexity
+    Linear,
+    /// Mild for simple and for very complex queries, steep in between
+    Sigmoid,
+    /// Saturating `1 - e^(-3c)`: moderately complex queries are already
+    /// penalized heavily
+    Exponential,
+}
+
+/// Confidence lost to query complexity: `strength * curve(complexity)`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ComplexityPenalty {
+    pub curve: PenaltyCurve,
+    /// Penalty at maximum complexity
+    pub strength: f32,
+}
+
+impl Default for ComplexityPenalty {
+    fn default() -> Self {
+        Self {
+            curve: PenaltyCurve::Linear,
+            strength: 0.3,
+        }
+    }
+}
+
+impl ComplexityPenalty {
+    pub fn new(curve: PenaltyCurve, strength: f32) -> Self {
+        Self { curve, strength }
+    }
+
+    /// Confidence penalty for a complexity score in `[0, 1]`
+    pub fn penalty(&self, complexity: f32) -> f32 {
+        let c = complexity.clamp(0.0, 1.0);
+        let shaped = match self.curve {
+            PenaltyCurve::Linear => c,
+            PenaltyCurve::Sigmoid => {
+                let sigmoid = |x: f32| 1.0 / (1.0 + (-10.0 * (x - 0.5)).exp());
+                (sigmoid(c) - sigmoid(0.0)) / (sigmoid(1.0) - sigmoid(0.0))
+            }
+            PenaltyCurve::Exponential => (1.0 - (-3.0 * c).exp()) / (1.0 - (-3.0f32).exp()),
+        };
+        self.strength * shaped
+    }
 }
 
 impl ReflectionEngine {
@@ -17,61 +153,331 @@ impl ReflectionEngine {
             model: Arc::new(RwLock::new(ReflectionModel::new())),
             quantum_reflector: QuantumReflector::new(),
             reflection_depth,
+            deterministic: false,
+            max_total_steps: None,
+            steps_taken: AtomicUsize::new(0),
+            confidence_floor: 0.0,
+            confidence_ceiling: 1.0,
+            critique_threshold: None,
+            governance_check: None,
+            complexity_penalty: ComplexityPenalty::default(),
+            cache: None,
+            cache_hits: AtomicUsize::new(0),
+            cache_misses: AtomicUsize::new(0),
+            counters: InsightCounters::default(),
+            observer: None,
+        }
+    }
+
+    /// Engine with a fixed sampler seed, producing identical step outputs and
+    /// confidences for identical queries (for golden-file tests and CI)
+    pub fn deterministic(reflection_depth: usize) -> Self {
+        Self {
+            quantum_reflector: QuantumReflector::with_seed(DETERMINISTIC_SEED),
+            deterministic: true,
+            ..Self::new(reflection_depth)
+        }
+    }
+
+    /// Keep the results of the `capacity` most recently used queries and
+    /// return them for repeated identical queries without re-running the
+    /// pipeline (or recording new steps)
+    ///
+    /// Only deterministic engines consult the cache; on a stochastic engine a
+    /// cached result would hide the sampling variance, so it is ignored.
+    pub fn with_cache(mut self, capacity: usize) -> Self {
+        self.cache = Some(Mutex::new(ResultCache::new(capacity)));
+        self
+    }
+
+    /// Invoke `observer` for every step this engine produces
+    pub fn with_observer(mut self, observer: impl ReflectionObserver + 'static) -> Self {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    /// Keep only the `capacity` most recent steps in the shared model's trace,
+    /// so long-running services don't grow it without bound
+    pub fn with_trace_capacity(self, capacity: usize) -> Result<Self, ReflectionError> {
+        self.update_model(|model| model.set_trace_capacity(capacity))?;
+        Ok(self)
+    }
+
+    /// Drop suggestions below `min_priority` whenever the shared model
+    /// regenerates them
+    pub fn with_suggestion_floor(self, min_priority: f32) -> Result<Self, ReflectionError> {
+        self.update_model(|model| model.suggestion_floor = Some(min_priority))?;
+        Ok(self)
+    }
+
+    /// Cap the reasoning steps this engine will take over its lifetime
+    ///
+    /// A reflection only starts if every step it could take fits in what is
+    /// left of the budget, so concurrent reflections never overrun it.
+    pub fn with_max_total_steps(mut self, max_total_steps: usize) -> Self {
+        self.max_total_steps = Some(max_total_steps);
+        self
+    }
+
+    /// Follow meta-reasoning with a `Critique` step, listing the assumptions
+    /// behind the conclusion, whenever the final confidence is below `threshold`
+    pub fn with_critique(mut self, threshold: f32) -> Self {
+        self.critique_threshold = Some(threshold);
+        self
+    }
+
+    /// Run the partial trace past `rules` after meta-reasoning, in a
+    /// `Validation` step whose confidence (which becomes the final confidence)
+    /// drops by 0.2 per governance error and 0.1 per warning
+    pub fn with_governance_check(mut self, rules: ReflectionRules) -> Self {
+        self.governance_check = Some(ReflectionGovernance::new(rules));
+        self
+    }
+
+    /// Shape and strength of the penalty query complexity takes off the
+    /// complexity step's confidence (default: linear, 0.3)
+    pub fn with_complexity_penalty(mut self, penalty: ComplexityPenalty) -> Self {
+        self.complexity_penalty = penalty;
+        self
+    }
+
+    /// Narrow the range step confidences are clamped into (within `[0, 1]`)
+    ///
+    /// Fails with [`ReflectionError::InvalidConfidenceBounds`] unless
+    /// `0 <= floor <= ceiling <= 1` (NaN bounds are rejected too).
+    pub fn with_confidence_bounds(mut self, floor: f32, ceiling: f32) -> Result<Self, ReflectionError> {
+        if !((0.0..=ceiling).contains(&floor) && ceiling <= 1.0) {
+            return Err(ReflectionError::InvalidConfidenceBounds { floor, ceiling });
         }
+        self.confidence_floor = floor;
+        self.confidence_ceiling = ceiling;
+        Ok(self)
+    }
+
+    /// Clamp a step's confidence into the configured bounds; NaN maps to the floor
+    fn bounded(&self, mut step: ReasoningStep) -> ReasoningStep {
+        step.confidence = if step.confidence.is_nan() {
+            self.confidence_floor
+        } else {
+            step.confidence.clamp(self.confidence_floor, self.confidence_ceiling)
+        };
+        step
+    }
+
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Reasoning steps taken by `reflect_on_query` so far, including steps
+    /// reserved by reflections still in flight
+    pub fn steps_taken(&self) -> usize {
+        self.steps_taken.load(Ordering::SeqCst)
     }
 
     /// Execute meta-cognitive reasoning on a query
-    pub fn reflect_on_query(&self, query: &str) -> Result<ReflectionResult> {
+    ///
+    /// Fails with [`ReflectionError::EmptyQuery`] for blank queries and with
+    /// [`ReflectionError::BudgetExhausted`] once the steps it could take no
+    /// longer fit in `max_total_steps`.
+    pub fn reflect_on_query(&self, query: &str) -> Result<ReflectionResult, ReflectionError> {
+        let cache = match self.cache {
+            Some(ref cache) if self.deterministic => cache,
+            _ => return self.reflect_with_evidence(query, None),
+        };
+
+        if let Some(cached) = lock_cache(cache)?.get(query) {
+            self.cache_hits.fetch_add(1, Ordering::SeqCst);
+            return Ok(cached);
+        }
+        self.cache_misses.fetch_add(1, Ordering::SeqCst);
+
+        let result = self.reflect_with_evidence(query, None)?;
+        lock_cache(cache)?.insert(query, result.clone());
+        Ok(result)
+    }
+
+    /// Reflect on a query grounded in a graph: nodes whose names share a term
+    /// with the query seed a `Retrieval` step whose confidence reflects their
+    /// evidence strength
+    pub fn reflect_with_graph(&self, query: &str, graph: &BioGraph) -> Result<ReflectionResult, ReflectionError> {
+        self.reflect_with_evidence(query, self.retrieve_evidence(query, graph))
+    }
+
+    fn reflect_with_evidence(
+        &self,
+        query: &str,
+        retrieval: Option<ReasoningStep>,
+    ) -> Result<ReflectionResult, ReflectionError> {
+        if query.trim().is_empty() {
+            return Err(ReflectionError::EmptyQuery);
+        }
+
+        let reserved = self.max_pipeline_steps(retrieval.is_some());
+        self.reserve_steps(reserved)?;
+        let result = self.run_pipeline(query, retrieval);
+
+        // Hand back the reserved steps the reflection did not take (all of
+        // them if it failed)
+        let taken = result.as_ref().map_or(0, |r| r.steps.len());
+        self.steps_taken.fetch_sub(reserved - taken, Ordering::SeqCst);
+        result
+    }
+
+    /// Most steps one reflection can take: complexity, quantum sampling and
+    /// meta-reasoning, plus retrieval, governance and critique when enabled
+    fn max_pipeline_steps(&self, retrieval: bool) -> usize {
+        3 + usize::from(retrieval)
+            + usize::from(self.governance_check.is_some())
+            + usize::from(self.critique_threshold.is_some())
+    }
+
+    /// Claim `steps` against `max_total_steps` in one atomic update, failing
+    /// without claiming anything if they don't all fit
+    fn reserve_steps(&self, steps: usize) -> Result<(), ReflectionError> {
+        let Some(budget) = self.max_total_steps else {
+            self.steps_taken.fetch_add(steps, Ordering::SeqCst);
+            return Ok(());
+        };
+        self.steps_taken
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |taken| {
+                (taken + steps <= budget).then_some(taken + steps)
+            })
+            .map(|_| ())
+            .map_err(|steps_taken| BudgetExhausted { budget, steps_taken }.into())
+    }
+
+    fn run_pipeline(
+        &self,
+        query: &str,
+        retrieval: Option<ReasoningStep>,
+    ) -> Result<ReflectionResult, ReflectionError> {
         let mut steps = Vec::new();
 
         // Step 1: Analyze query complexity
-        let complexity_step = self.analyze_complexity(query)?;
-        steps.push(complexity_step.clone());
+        self.push_step(&mut steps, self.analyze_complexity(query));
+
+        // Graph evidence, when reflecting against a graph
+        if let Some(step) = retrieval {
+            self.push_step(&mut steps, step);
+        }
 
         // Step 2: Quantum-inspired reflection
-        let quantum_step = self.quantum_reflector.reflect(query)?;
-        steps.push(quantum_step.clone());
+        let quantum_step = self
+            .quantum_reflector
+            .reflect(query)
+            .map_err(ReflectionError::SamplingFailed)?;
+        self.push_step(&mut steps, quantum_step);
 
         // Step 3: Meta-reasoning
-        let meta_step = self.meta_reason(&steps)?;
-        steps.push(meta_step.clone());
+        let meta_step = self.meta_reason(&steps);
+        let mut final_confidence = self.push_step(&mut steps, meta_step);
+
+        // Governance cross-check of the reasoning so far
+        if let Some(governance) = &self.governance_check {
+            let validation_step = self.validate_trace(&steps, governance);
+            final_confidence = self.push_step(&mut steps, validation_step);
+        }
+
+        // Step 4: Self-critique of low-confidence conclusions
+        if let Some(threshold) = self.critique_threshold {
+            if final_confidence < threshold {
+                let critique_step = self.critique(&steps, threshold);
+                self.push_step(&mut steps, critique_step);
+            }
+        }
 
         // Update model
-        {
-            let mut model = self.model.write().unwrap();
+        let insights = self.update_model(|model| {
             for step in &steps {
                 model.add_step(step.clone());
             }
             model.generate_suggestions();
-        }
+            model.get_insights()
+        })?;
+        let insights = crate::model::MetaCognitiveInsights {
+            cache_hits: self.cache_hits.load(Ordering::SeqCst),
+            cache_misses: self.cache_misses.load(Ordering::SeqCst),
+            ..insights
+        };
 
         Ok(ReflectionResult {
             steps,
-            final_confidence: meta_step.confidence,
-            insights: self.get_insights(),
+            final_confidence,
+            insights,
         })
     }
 
+    /// Clamp a completed step, report it to the observer and append it;
+    /// returns its clamped confidence
+    fn push_step(&self, steps: &mut Vec<ReasoningStep>, step: ReasoningStep) -> f32 {
+        let step = self.bounded(step);
+        if let Some(observer) = &self.observer {
+            observer.on_step(&step);
+        }
+        let confidence = step.confidence;
+        steps.push(step);
+        confidence
+    }
+
     /// Analyze query complexity
-    fn analyze_complexity(&self, query: &str) -> Result<ReasoningStep> {
+    fn analyze_complexity(&self, query: &str) -> ReasoningStep {
         let word_count = query.split_whitespace().count();
+        let char_count = query.chars().count();
         let complexity_score = (word_count as f32 / 50.0).min(1.0);
 
         let output = format!(
-            "Query complexity: {:.2} (words: {})",
-            complexity_score, word_count
+            "Query complexity: {:.2} (words: {}, chars: {})",
+            complexity_score, word_count, char_count
         );
 
-        Ok(ReasoningStep::new(
+        ReasoningStep::new(
             StepType::Query,
             query.to_string(),
             output,
-            1.0 - complexity_score * 0.3,
-        ))
+            1.0 - self.complexity_penalty.penalty(complexity_score),
+        )
+    }
+
+    /// Retrieval step over graph nodes matching query terms, if any match
+    ///
+    /// Confidence saturates the mean `node_evidence_score` of the matched
+    /// nodes as `1 - exp(-score)`.
+    fn retrieve_evidence(&self, query: &str, graph: &BioGraph) -> Option<ReasoningStep> {
+        let query_terms = terms(query);
+        let matched: Vec<(uuid::Uuid, &str)> = graph
+            .labelled_nodes()
+            .into_iter()
+            .filter(|(_, name, _)| !terms(name).is_disjoint(&query_terms))
+            .map(|(id, name, _)| (id, name))
+            .collect();
+        if matched.is_empty() {
+            return None;
+        }
+
+        let evidence = matched
+            .iter()
+            .map(|(id, _)| graph.node_evidence_score(*id))
+            .sum::<f32>()
+            / matched.len() as f32;
+        let names: Vec<&str> = matched.iter().map(|(_, name)| *name).collect();
+
+        let mut step = ReasoningStep::new(
+            StepType::Retrieval,
+            query.to_string(),
+            format!(
+                "Retrieved {} graph node(s) ({}), evidence score: {:.2}",
+                matched.len(),
+                names.join(", "),
+                evidence
+            ),
+            1.0 - (-evidence).exp(),
+        );
+        step.metadata.insert("matched_nodes".to_string(), names.join(","));
+        Some(step)
     }
 
     /// Meta-reasoning on previous steps
-    fn meta_reason(&self, steps: &[ReasoningStep]) -> Result<ReasoningStep> {
+    fn meta_reason(&self, steps: &[ReasoningStep]) -> ReasoningStep {
         let avg_confidence = steps.iter().map(|s| s.confidence).sum::<f32>() / steps.len() as f32;
 
         let output = format!(
@@ -80,61 +486,464 @@ impl ReflectionEngine {
             avg_confidence
         );
 
-        Ok(ReasoningStep::new(
+        ReasoningStep::new(
             StepType::Reasoning,
             format!("{} previous steps", steps.len()),
             output,
             avg_confidence,
-        ))
+        )
+    }
+
+    /// Validate the steps taken so far as if they were the whole trace,
+    /// lowering the conclusion's confidence for every issue governance raises
+    fn validate_trace(&self, steps: &[ReasoningStep], governance: &ReflectionGovernance) -> ReasoningStep {
+        let mut partial = ReflectionModel::new();
+        for step in steps {
+            partial.add_step(step.clone());
+        }
+        let validation = governance.validate_reflection(&partial);
+
+        let conclusion = steps.last().map(|s| s.confidence).unwrap_or(0.0);
+        let penalty = GOVERNANCE_ERROR_PENALTY * validation.errors.len() as f32
+            + GOVERNANCE_WARNING_PENALTY * validation.warnings.len() as f32;
+        let issues: Vec<&str> = validation
+            .errors
+            .iter()
+            .chain(&validation.warnings)
+            .map(String::as_str)
+            .collect();
+
+        let output = if issues.is_empty() {
+            format!("Governance check: {} steps pass", steps.len())
+        } else {
+            format!("Governance check: {}", issues.join("; "))
+        };
+        let mut step = ReasoningStep::new(
+            StepType::Validation,
+            format!("{} previous steps", steps.len()),
+            output,
+            (conclusion - penalty).max(0.0),
+        );
+        step.metadata.insert("governance_errors".to_string(), validation.errors.len().to_string());
+        step.metadata.insert("governance_warnings".to_string(), validation.warnings.len().to_string());
+        step
+    }
+
+    /// Challenge a low-confidence conclusion by spelling out the assumption
+    /// each preceding step rests on, weakest first
+    fn critique(&self, steps: &[ReasoningStep], threshold: f32) -> ReasoningStep {
+        let conclusion = steps.last().map(|s| s.confidence).unwrap_or(0.0);
+        let mut premises: Vec<&ReasoningStep> = steps[..steps.len().saturating_sub(1)].iter().collect();
+        premises.sort_by(|a, b| a.confidence.total_cmp(&b.confidence));
+        let assumptions: Vec<String> = premises
+            .iter()
+            .map(|s| format!("{} ({:.2})", assumption_for(&s.step_type), s.confidence))
+            .collect();
+
+        let mut step = ReasoningStep::new(
+            StepType::Critique,
+            format!("conclusion at confidence {:.2}", conclusion),
+            format!(
+                "Critique: confidence {:.2} is below {:.2}; assumptions: {}",
+                conclusion,
+                threshold,
+                assumptions.join("; ")
+            ),
+            conclusion,
+        );
+        step.metadata.insert("assumptions".to_string(), assumptions.join("; "));
+        step
+    }
+
+    /// Warm-start the model from submissions already accepted by the hub, so
+    /// insights reflect existing evidence before any query is made
+    pub fn seed_from_submissions(&self, submissions: &[Submission]) -> Result<(), ReflectionError> {
+        self.update_model(|model| {
+            for submission in submissions {
+                let mut step = ReasoningStep::new(
+                    StepType::Retrieval,
+                    submission.id.clone(),
+                    format!("Prior evidence: {}", submission.content),
+                    submission.confidence,
+                );
+                step.metadata.insert("source".to_string(), "hub_submission".to_string());
+                step.metadata.insert("provenance".to_string(), submission.provenance.join(","));
+                model.add_step(self.bounded(step));
+            }
+            model.generate_suggestions();
+        })
     }
 
     /// Get current insights
+    ///
+    /// Read from figures the engine re-derives from the model after every
+    /// write, so insights never wait on reflections in flight.
     pub fn get_insights(&self) -> crate::model::MetaCognitiveInsights {
-        let model = self.model.read().unwrap();
-        model.get_insights()
+        let counters = &self.counters;
+        crate::model::MetaCognitiveInsights {
+            total_steps: counters.total_steps.load(Ordering::SeqCst),
+            average_confidence: f32::from_bits(counters.average_confidence.load(Ordering::SeqCst)),
+            total_errors: counters.total_errors.load(Ordering::SeqCst),
+            unique_error_types: counters.unique_error_types.load(Ordering::SeqCst),
+            suggestions_count: counters.suggestions_count.load(Ordering::SeqCst),
+            cache_hits: self.cache_hits.load(Ordering::SeqCst),
+            cache_misses: self.cache_misses.load(Ordering::SeqCst),
+        }
+    }
+
+    /// Re-derive the insight progression over a stored model's trace, one
+    /// snapshot per step, without re-running the (stochastic) engine
+    ///
+    /// Errors are not tied to steps in the trace, so every snapshot carries
+    /// the stored error counts.
+    pub fn replay(&self, model: &ReflectionModel) -> Vec<crate::model::MetaCognitiveInsights> {
+        let mut replayed = ReflectionModel {
+            error_patterns: model.error_patterns.clone(),
+            ..ReflectionModel::new()
+        };
+
+        model
+            .reasoning_trace
+            .iter()
+            .map(|step| {
+                replayed.add_step(step.clone());
+                replayed.generate_suggestions();
+                replayed.get_insights()
+            })
+            .collect()
     }
 
     /// Record an error for learning
-    pub fn record_error(&self, error_type: String) {
-        let mut model = self.model.write().unwrap();
-        model.record_error(error_type);
+    pub fn record_error(&self, error_type: String) -> Result<(), ReflectionError> {
+        self.update_model(|model| model.record_error(error_type))
     }
 
     /// Get improvement suggestions
-    pub fn get_suggestions(&self) -> Vec<crate::model::Suggestion> {
-        let model = self.model.read().unwrap();
-        model.improvement_suggestions.clone()
+    pub fn get_suggestions(&self) -> Result<Vec<crate::model::Suggestion>, ReflectionError> {
+        Ok(self.read_model()?.improvement_suggestions.clone())
+    }
+
+    /// Read access to the shared model, failing if a writer panicked
+    pub fn read_model(&self) -> Result<RwLockReadGuard<'_, ReflectionModel>, ReflectionError> {
+        self.model.read().map_err(|_| ReflectionError::LockPoisoned { lock: "model" })
+    }
+
+    /// Handle for reading the model after this engine has been moved
+    pub fn model_reader(&self) -> ModelReader {
+        ModelReader(Arc::clone(&self.model))
+    }
+
+    /// Change the shared model under its write lock, then refresh the
+    /// figures `get_insights` serves from it
+    pub fn update_model<R>(&self, update: impl FnOnce(&mut ReflectionModel) -> R) -> Result<R, ReflectionError> {
+        let mut model = self.write_model()?;
+        let result = update(&mut model);
+        self.counters.refresh(&model);
+        Ok(result)
+    }
+
+    fn write_model(&self) -> Result<RwLockWriteGuard<'_, ReflectionModel>, ReflectionError> {
+        self.model.write().map_err(|_| ReflectionError::LockPoisoned { lock: "model" })
+    }
+
+    /// Explain a reflection result in plain language for reviewers
+    pub fn explain(&self, result: &ReflectionResult) -> String {
+        let mut lines = vec![format!(
+            "Final confidence {:.2} was derived from {} reasoning steps:",
+            result.final_confidence,
+            result.steps.len()
+        )];
+
+        for (i, step) in result.steps.iter().enumerate() {
+            lines.push(format!(
+                "  {}. {:?} step (confidence {:.2}): {}",
+                i + 1,
+                step.step_type,
+                step.confidence,
+                step.output
+            ));
+        }
+        lines.extend(confidence_notes(result));
+
+        lines.join("\n")
+    }
+
+    /// Markdown rendering of `explain`: a summary, a table of steps and the
+    /// same confidence notes
+    pub fn explain_markdown(&self, result: &ReflectionResult) -> String {
+        let mut lines = vec![
+            "## Reflection".to_string(),
+            String::new(),
+            format!("- **Final confidence:** {:.2}", result.final_confidence),
+            format!("- **Steps:** {}", result.steps.len()),
+            String::new(),
+            "| # | Step | Confidence | Output |".to_string(),
+            "|---|------|------------|--------|".to_string(),
+        ];
+
+        for (i, step) in result.steps.iter().enumerate() {
+            lines.push(format!(
+                "| {} | {:?} | {:.2} | {} |",
+                i + 1,
+                step.step_type,
+                step.confidence,
+                step.output.replace('|', "\\|").replace('\n', " ")
+            ));
+        }
+
+        let notes = confidence_notes(result);
+        if !notes.is_empty() {
+            lines.push(String::new());
+            lines.extend(notes);
+        }
+
+        lines.join("\n")
     }
 
     /// Perform deep reflection (recursive meta-reasoning)
-    pub fn deep_reflect(&self, query: &str) -> Result<DeepReflectionResult> {
-        let mut reflection_layers = Vec::new();
+    pub fn deep_reflect(&self, query: &str) -> Result<DeepReflectionResult, ReflectionError> {
+        self.extend_layers(Vec::new(), query.to_string(), self.reflection_depth)
+    }
+
+    /// Extend a previous deep reflection by up to `additional_depth` layers,
+    /// seeding the next layer from the last existing one
+    pub fn deep_reflect_continue(
+        &self,
+        prior: &DeepReflectionResult,
+        additional_depth: usize,
+    ) -> Result<DeepReflectionResult, ReflectionError> {
+        let last = prior.layers.last().ok_or(ReflectionError::NoLayers)?;
+        self.extend_layers(prior.layers.clone(), next_layer_query(last), additional_depth)
+    }
 
-        let mut current_query = query.to_string();
-        for depth in 0..self.reflection_depth {
+    fn extend_layers(
+        &self,
+        mut reflection_layers: Vec<ReflectionResult>,
+        mut current_query: String,
+        depth: usize,
+    ) -> Result<DeepReflectionResult, ReflectionError> {
+        for _ in 0..depth {
             let result = self.reflect_on_query(&current_query)?;
-            reflection_layers.push(result.clone());
 
             // Use insights as input for next layer
-            current_query = format!(
-                "Reflect on: confidence={:.2}, steps={}",
-                result.final_confidence,
-                result.steps.len()
-            );
+            current_query = next_layer_query(&result);
+            let confident = result.final_confidence > 0.9;
+            reflection_layers.push(result);
 
             // Stop if confidence is high enough
-            if result.final_confidence > 0.9 {
+            if confident {
                 break;
             }
         }
 
+        let final_depth = reflection_layers.len();
         Ok(DeepReflectionResult {
             layers: reflection_layers,
-            final_depth: reflection_layers.len(),
+            final_depth,
         })
     }
 }
 
+/// Where a result's confidence was weakest, for `explain` and `explain_markdown`
+fn confidence_notes(result: &ReflectionResult) -> Vec<String> {
+    let mut notes = vec![];
+
+    let lowest = result
+        .steps
+        .iter()
+        .min_by(|a, b| a.confidence.partial_cmp(&b.confidence).unwrap_or(std::cmp::Ordering::Equal));
+    if let Some(step) = lowest {
+        notes.push(format!(
+            "Lowest confidence came from the {:?} step at {:.2}.",
+            step.step_type, step.confidence
+        ));
+    }
+
+    let low_points = result.steps.iter().filter(|s| s.confidence < 0.6).count();
+    if low_points > 0 {
+        notes.push(format!(
+            "{} step(s) fell below 0.60 and pulled the final confidence down.",
+            low_points
+        ));
+    }
+
+    notes
+}
+
+/// The assumption a step of the given type takes for granted
+fn assumption_for(step_type: &StepType) -> &'static str {
+    match step_type {
+        StepType::Query => "the query is unambiguous and self-contained",
+        StepType::Retrieval => "the matched graph nodes are the relevant evidence",
+        StepType::Reasoning => "averaging step confidences reflects overall support",
+        StepType::Validation => "the sampled reasoning paths are representative",
+        StepType::Synthesis => "the combined findings are mutually consistent",
+        StepType::Critique => "earlier objections have been addressed",
+    }
+}
+
+/// Lowercased alphanumeric terms of at least three characters
+fn terms(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| t.len() >= 3)
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+/// Neutralize bracketed context markers (e.g. `[Evidence items: N]`) in user
+/// text by turning square brackets into parentheses, so a query enriched with
+/// real markers cannot carry spoofed ones
+pub fn sanitize_query(input: &str) -> String {
+    input.replace('[', "(").replace(']', ")")
+}
+
+/// Query fed to the layer after `result` in a deep reflection
+fn next_layer_query(result: &ReflectionResult) -> String {
+    format!(
+        "Reflect on: confidence={:.2}, steps={}",
+        result.final_confidence,
+        result.steps.len()
+    )
+}
+
+/// Cheaply clonable handle to one engine, for sharing across threads or
+/// server handlers
+///
+/// The engine is `Send + Sync`: reflections run concurrently and only hold
+/// the model's write lock while appending their finished steps, while
+/// `steps_taken` and `get_insights` read atomics and never wait on that lock.
+#[derive(Clone)]
+pub struct SharedReflectionEngine(Arc<ReflectionEngine>);
+
+impl SharedReflectionEngine {
+    pub fn new(engine: ReflectionEngine) -> Self {
+        Self(Arc::new(engine))
+    }
+}
+
+impl From<ReflectionEngine> for SharedReflectionEngine {
+    fn from(engine: ReflectionEngine) -> Self {
+        Self::new(engine)
+    }
+}
+
+impl std::ops::Deref for SharedReflectionEngine {
+    type Target = ReflectionEngine;
+
+    fn deref(&self) -> &ReflectionEngine {
+        &self.0
+    }
+}
+
+/// Why a reflection failed
+#[derive(Debug)]
+pub enum ReflectionError {
+    /// The query was empty or whitespace only (a client error)
+    EmptyQuery,
+    /// Quantum sampling could not produce its reflection step
+    SamplingFailed(anyhow::Error),
+    /// A thread panicked while holding one of the engine's locks (`model`,
+    /// `result cache`) or the API's `quality trend` lock
+    LockPoisoned { lock: &'static str },
+    /// The engine's `max_total_steps` budget is used up
+    BudgetExhausted(BudgetExhausted),
+    /// `deep_reflect_continue` was given a reflection with no layers
+    NoLayers,
+    /// `with_confidence_bounds` was given bounds outside `0 <= floor <= ceiling <= 1`
+    InvalidConfidenceBounds { floor: f32, ceiling: f32 },
+}
+
+impl std::fmt::Display for ReflectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyQuery => write!(f, "Query is empty"),
+            Self::SamplingFailed(e) => write!(f, "Quantum sampling failed: {}", e),
+            Self::LockPoisoned { lock } => write!(f, "Reflection {} lock poisoned", lock),
+            Self::BudgetExhausted(e) => e.fmt(f),
+            Self::NoLayers => write!(f, "Cannot continue a deep reflection with no layers"),
+            Self::InvalidConfidenceBounds { floor, ceiling } => write!(
+                f,
+                "Confidence bounds must satisfy 0 <= floor <= ceiling <= 1, got [{}, {}]",
+                floor, ceiling
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReflectionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::SamplingFailed(e) => Some(e.as_ref()),
+            Self::BudgetExhausted(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<BudgetExhausted> for ReflectionError {
+    fn from(error: BudgetExhausted) -> Self {
+        Self::BudgetExhausted(error)
+    }
+}
+
+fn lock_cache(cache: &Mutex<ResultCache>) -> Result<std::sync::MutexGuard<'_, ResultCache>, ReflectionError> {
+    cache.lock().map_err(|_| ReflectionError::LockPoisoned { lock: "result cache" })
+}
+
+/// Returned when an engine's `max_total_steps` budget is used up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetExhausted {
+    pub budget: usize,
+    pub steps_taken: usize,
+}
+
+impl std::fmt::Display for BudgetExhausted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Reflection budget exhausted: {} of {} steps used",
+            self.steps_taken, self.budget
+        )
+    }
+}
+
+impl std::error::Error for BudgetExhausted {}
+
+/// Least-recently-used cache of reflection results keyed by exact query
+struct ResultCache {
+    capacity: usize,
+    /// Least recently used first
+    entries: VecDeque<(String, ReflectionResult)>,
+}
+
+impl ResultCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::new(),
+        }
+    }
+
+    fn get(&mut self, query: &str) -> Option<ReflectionResult> {
+        let idx = self.entries.iter().position(|(q, _)| q == query)?;
+        let entry = self.entries.remove(idx)?;
+        let result = entry.1.clone();
+        self.entries.push_back(entry);
+        Some(result)
+    }
+
+    fn insert(&mut self, query: &str, result: ReflectionResult) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.entries.retain(|(q, _)| q != query);
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((query.to_string(), result));
+    }
+}
+
 /// Result of reflection
 #[derive(Debug, Clone)]
 pub struct ReflectionResult {
@@ -5,8 +5,13 @@ pub mod govern;
 pub mod model;
 pub mod quantum;
 
-pub use api::{create_router, ReflectionApiState};
-pub use engine::{ReflectionEngine, ReflectionResult, DeepReflectionResult};
+pub use api::{create_router, create_router_with_state, error_status, ReflectionApiState, DEFAULT_MAX_BODY_BYTES,
+    DEFAULT_QUALITY_TREND_CAPACITY,
+};
+pub use engine::{
+    sanitize_query, BudgetExhausted, ComplexityPenalty, DeepReflectionResult, ModelReader, PenaltyCurve,
+    ReflectionEngine, ReflectionError, ReflectionObserver, ReflectionResult, SharedReflectionEngine,
+};
 pub use govern::{ReflectionGovernance, ReflectionRules, ReflectionValidation, QualityReport};
 pub use model::{ReflectionModel, ReasoningStep, StepType, Suggestion, SuggestionType, MetaCognitiveInsights};
-pub use quantum::QuantumReflector;
+pub use quantum::{QuantumReflector, SamplingStrategy};
@@ -1,33 +1,91 @@
 // crates/limit-reflection/src/model.rs
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 /// Meta-cognitive reasoning model for self-reflection
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReflectionModel {
     pub id: Uuid,
-    pub reasoning_trace: Vec<ReasoningStep>,
-    pub confidence_history: Vec<f32>,
+    pub reasoning_trace: VecDeque<ReasoningStep>,
+    pub confidence_history: VecDeque<f32>,
     pub error_patterns: HashMap<String, usize>,
     pub improvement_suggestions: Vec<Suggestion>,
+    /// Keep at most this many recent steps; older ones are evicted but still
+    /// counted in the insights
+    #[serde(default)]
+    pub trace_capacity: Option<usize>,
+    /// Steps evicted from the trace so far, and the sum of their confidences
+    #[serde(default)]
+    pub evicted_steps: usize,
+    #[serde(default)]
+    pub evicted_confidence: f32,
+    /// When set, suggestions below this priority are pruned every time
+    /// suggestions are regenerated
+    #[serde(default)]
+    pub suggestion_floor: Option<f32>,
 }
 
 impl ReflectionModel {
     pub fn new() -> Self {
         Self {
             id: Uuid::new_v4(),
-            reasoning_trace: vec![],
-            confidence_history: vec![],
+            reasoning_trace: VecDeque::new(),
+            confidence_history: VecDeque::new(),
             error_patterns: HashMap::new(),
             improvement_suggestions: vec![],
+            trace_capacity: None,
+            evicted_steps: 0,
+            evicted_confidence: 0.0,
+            suggestion_floor: None,
         }
     }
 
+    /// Bound the trace to the `capacity` most recent steps
+    pub fn with_trace_capacity(mut self, capacity: usize) -> Self {
+        self.set_trace_capacity(capacity);
+        self
+    }
+
+    /// Bound the trace to the `capacity` most recent steps, evicting any
+    /// excess immediately
+    pub fn set_trace_capacity(&mut self, capacity: usize) {
+        self.trace_capacity = Some(capacity);
+        self.evict();
+    }
+
     /// Add a reasoning step to the trace
     pub fn add_step(&mut self, step: ReasoningStep) {
-        self.confidence_history.push(step.confidence);
-        self.reasoning_trace.push(step);
+        self.confidence_history.push_back(step.confidence);
+        self.reasoning_trace.push_back(step);
+        self.evict();
+    }
+
+    /// Drop the oldest steps beyond `trace_capacity`, folding them into the
+    /// eviction counters
+    fn evict(&mut self) {
+        let Some(capacity) = self.trace_capacity else {
+            return;
+        };
+        while self.reasoning_trace.len() > capacity {
+            self.reasoning_trace.pop_front();
+            self.evicted_steps += 1;
+            self.evicted_confidence += self.confidence_history.pop_front().unwrap_or(0.0);
+        }
+    }
+
+    /// Number of retained trace steps of each type
+    pub fn step_type_counts(&self) -> HashMap<StepType, usize> {
+        let mut counts = HashMap::new();
+        for step in &self.reasoning_trace {
+            *counts.entry(step.step_type.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Remove suggestions whose priority is below `min_priority`
+    pub fn prune_suggestions(&mut self, min_priority: f32) {
+        self.improvement_suggestions.retain(|s| s.priority >= min_priority);
     }
 
     /// Record an error pattern
@@ -65,12 +123,36 @@ impl ReflectionModel {
                 });
             }
         }
+
+        // Challenge the latest conclusion if it was critiqued
+        if let Some(critique) = self
+            .reasoning_trace
+            .back()
+            .filter(|s| s.step_type == StepType::Critique)
+        {
+            self.improvement_suggestions.push(Suggestion {
+                id: Uuid::new_v4(),
+                suggestion_type: SuggestionType::EnhanceValidation,
+                description: format!(
+                    "Low-confidence conclusion. Validate its assumptions: {}",
+                    critique.metadata.get("assumptions").cloned().unwrap_or_default()
+                ),
+                priority: 0.7,
+            });
+        }
+
+        if let Some(floor) = self.suggestion_floor {
+            self.prune_suggestions(floor);
+        }
     }
 
     /// Get meta-cognitive insights
     pub fn get_insights(&self) -> MetaCognitiveInsights {
-        let avg_confidence = if !self.confidence_history.is_empty() {
-            self.confidence_history.iter().sum::<f32>() / self.confidence_history.len() as f32
+        // Lifetime figures: evicted steps still count
+        let total_steps = self.evicted_steps + self.reasoning_trace.len();
+        let scored_steps = self.evicted_steps + self.confidence_history.len();
+        let avg_confidence = if scored_steps > 0 {
+            (self.evicted_confidence + self.confidence_history.iter().sum::<f32>()) / scored_steps as f32
         } else {
             0.0
         };
@@ -78,11 +160,13 @@ impl ReflectionModel {
         let total_errors: usize = self.error_patterns.values().sum();
 
         MetaCognitiveInsights {
-            total_steps: self.reasoning_trace.len(),
+            total_steps,
             average_confidence: avg_confidence,
             total_errors,
             unique_error_types: self.error_patterns.len(),
             suggestions_count: self.improvement_suggestions.len(),
+            cache_hits: 0,
+            cache_misses: 0,
         }
     }
 }
@@ -119,13 +203,14 @@ impl ReasoningStep {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum StepType {
     Query,
     Retrieval,
     Reasoning,
     Validation,
     Synthesis,
+    Critique,
 }
 
 /// Improvement suggestion
@@ -154,4 +239,9 @@ pub struct MetaCognitiveInsights {
     pub total_errors: usize,
     pub unique_error_types: usize,
     pub suggestions_count: usize,
+    /// Reflection result cache lookups served / missed (engines with a cache)
+    #[serde(default)]
+    pub cache_hits: usize,
+    #[serde(default)]
+    pub cache_misses: usize,
 }
@@ -2,26 +2,58 @@
 use crate::model::{ReasoningStep, StepType};
 use anyhow::Result;
 use limit_quantum::QuantumSampler;
+use serde::{Deserialize, Serialize};
+
+/// How the reflector turns input probabilities into samples
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SamplingStrategy {
+    /// Sample directly from the input distribution
+    #[default]
+    Categorical,
+    /// Sample only among the k most probable states (renormalized)
+    TopK(usize),
+    /// Sample from the temperature-annealed (softmax) distribution
+    Annealed,
+}
 
 /// Quantum-inspired reflector for meta-cognitive reasoning
 pub struct QuantumReflector {
     sampler: QuantumSampler,
+    strategy: SamplingStrategy,
 }
 
 impl QuantumReflector {
     pub fn new() -> Self {
         Self {
             sampler: QuantumSampler::new(0.5, 100),
+            strategy: SamplingStrategy::default(),
+        }
+    }
+
+    /// Reflector whose sampling is reproducible for identical inputs
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            sampler: QuantumSampler::new(0.5, 100).with_seed(seed),
+            strategy: SamplingStrategy::default(),
         }
     }
 
+    pub fn with_strategy(mut self, strategy: SamplingStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn strategy(&self) -> SamplingStrategy {
+        self.strategy
+    }
+
     /// Perform quantum-inspired reflection
     pub fn reflect(&self, input: &str) -> Result<ReasoningStep> {
         // Create probability distribution based on input characteristics
         let probabilities = self.compute_probabilities(input);
 
         // Sample using quantum-inspired approach
-        let samples = self.sampler.sample(&probabilities);
+        let samples = self.sampler.sample(&self.sampling_distribution(&probabilities)?);
 
         // Compute reflection confidence
         let confidence = self.compute_confidence(&samples, &probabilities);
@@ -41,8 +73,11 @@ impl QuantumReflector {
     }
 
     /// Compute probability distribution from input
-    fn compute_probabilities(&self, input: &str) -> Vec<f32> {
-        let len = input.len();
+    ///
+    /// Length features count characters rather than bytes so that non-ASCII
+    /// text (e.g. Greek variant names) is not over-weighted.
+    pub fn compute_probabilities(&self, input: &str) -> Vec<f32> {
+        let len = input.chars().count();
         let word_count = input.split_whitespace().count();
 
         // Create distribution based on input features
@@ -56,6 +91,33 @@ impl QuantumReflector {
         .collect()
     }
 
+    /// Reshape the input distribution according to the sampling strategy
+    fn sampling_distribution(&self, probabilities: &[f32]) -> Result<Vec<f32>> {
+        Ok(match self.strategy {
+            SamplingStrategy::Categorical => probabilities.to_vec(),
+            SamplingStrategy::Annealed => self.sampler.anneal(probabilities)?,
+            SamplingStrategy::TopK(k) => {
+                let mut ranked: Vec<usize> = (0..probabilities.len()).collect();
+                ranked.sort_by(|&a, &b| {
+                    probabilities[b]
+                        .partial_cmp(&probabilities[a])
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+
+                let mut top_k = vec![0.0; probabilities.len()];
+                for &idx in ranked.iter().take(k) {
+                    top_k[idx] = probabilities[idx];
+                }
+
+                let sum: f32 = top_k.iter().sum();
+                if sum > 0.0 {
+                    top_k.iter_mut().for_each(|p| *p /= sum);
+                }
+                top_k
+            }
+        })
+    }
+
     /// Compute confidence from samples
     fn compute_confidence(&self, samples: &[usize], probabilities: &[f32]) -> f32 {
         if samples.is_empty() {
@@ -86,7 +148,7 @@ impl QuantumReflector {
     }
 
     /// Perform quantum annealing for optimization
-    pub fn anneal_reflection(&self, probabilities: &[f32]) -> Vec<f32> {
+    pub fn anneal_reflection(&self, probabilities: &[f32]) -> Result<Vec<f32>> {
         self.sampler.anneal(probabilities)
     }
 }
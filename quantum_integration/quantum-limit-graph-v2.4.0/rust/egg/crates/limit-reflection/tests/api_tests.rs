@@ -0,0 +1,307 @@
+// tests/api_tests.rs
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+        Router,
+    };
+    use http_body_util::BodyExt;
+    use limit_reflection::{
+        create_router, create_router_with_state, ReflectionApiState, ReflectionEngine, Suggestion,
+        SuggestionType, DEFAULT_MAX_BODY_BYTES,
+    };
+    use serde_json::json;
+    use serde_json::Value;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    async fn send(app: &Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(match body {
+                Some(json) => Body::from(json.to_string()),
+                None => Body::empty(),
+            })
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let json = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+        (status, json)
+    }
+
+    fn create_test_suggestion(suggestion_type: SuggestionType, priority: f32) -> Suggestion {
+        Suggestion {
+            id: Uuid::new_v4(),
+            suggestion_type,
+            description: "Test suggestion".to_string(),
+            priority,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_suggestions_filtered_by_type() {
+        let state = ReflectionApiState::new();
+        state
+            .engine
+            .update_model(|model| {
+                model.improvement_suggestions.push(create_test_suggestion(SuggestionType::FixRecurringError, 0.9));
+                model.improvement_suggestions.push(create_test_suggestion(SuggestionType::IncreaseConfidence, 0.8));
+                model.improvement_suggestions.push(create_test_suggestion(SuggestionType::FixRecurringError, 0.7));
+            })
+            .unwrap();
+        let app = create_router_with_state(state);
+
+        let (status, all) = send(&app, "GET", "/suggestions", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(all["suggestions"].as_array().unwrap().len(), 3);
+        // Insights are derived from the same model
+        let (_, insights) = send(&app, "GET", "/insights", None).await;
+        assert_eq!(insights["suggestions_count"], 3);
+
+        let (status, filtered) = send(&app, "GET", "/suggestions?type=FixRecurringError", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let suggestions = filtered["suggestions"].as_array().unwrap();
+        assert_eq!(suggestions.len(), 2);
+        assert!(suggestions
+            .iter()
+            .all(|s| s["suggestion"]["suggestion_type"] == "FixRecurringError"));
+    }
+
+    #[tokio::test]
+    async fn test_suggestions_unknown_type() {
+        let app = create_router_with_state(ReflectionApiState::new());
+
+        let (status, _) = send(&app, "GET", "/suggestions?type=MakeItBetter", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_reflect_budget_exhausted() {
+        let mut state = ReflectionApiState::new();
+        state.engine = ReflectionEngine::new(3).with_max_total_steps(3);
+        let app = create_router_with_state(state);
+        let body = json!({ "query": "How does spike bind ACE2?" });
+
+        let (status, _) = send(&app, "POST", "/reflect", Some(body.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send(&app, "POST", "/reflect", Some(body)).await;
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn test_reflect_empty_query_is_bad_request() {
+        let app = create_router_with_state(ReflectionApiState::new());
+
+        let (status, _) = send(&app, "POST", "/reflect", Some(json!({ "query": "  " }))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_reflect_reports_governance_standards() {
+        let mut state = ReflectionApiState::new();
+        state.engine = ReflectionEngine::new(3).with_confidence_bounds(0.0, 0.3).unwrap();
+        let app = create_router_with_state(state);
+
+        let (status, body) = send(&app, "POST", "/reflect", Some(json!({ "query": "Spike binding?" }))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["meets_standards"], false);
+        let warnings = body["governance_warnings"].as_array().unwrap();
+        assert!(warnings
+            .iter()
+            .any(|w| w.as_str().unwrap().starts_with("Average confidence")));
+
+        let app = create_router_with_state(ReflectionApiState::new());
+        let (_, body) = send(&app, "POST", "/reflect", Some(json!({ "query": "Spike binding?" }))).await;
+        assert!(body["meets_standards"].is_boolean());
+        assert!(body["governance_warnings"].is_array());
+    }
+
+    #[tokio::test]
+    async fn test_reflect_markdown_format() {
+        let app = create_router_with_state(ReflectionApiState::new());
+        let request = Request::builder()
+            .method("POST")
+            .uri("/reflect?format=markdown")
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "query": "Spike binding?" }).to_string()))
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers()["content-type"].to_str().unwrap().starts_with("text/markdown"));
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+        assert!(serde_json::from_str::<Value>(&body).is_err());
+        assert!(body.starts_with("## Reflection"));
+        assert!(body.contains("- **Final confidence:** "));
+        assert!(body.contains("- **Steps:** 3"));
+        assert!(body.contains("| 1 | Query |"));
+
+        let (status, _) = send(&app, "POST", "/reflect?format=yaml", Some(json!({ "query": "Spike binding?" }))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_json_rpc_reflect() {
+        let app = create_router_with_state(ReflectionApiState::new());
+        let request = json!({
+            "jsonrpc": "2.0",
+            "method": "reflect",
+            "params": { "query": "How does spike bind ACE2?" },
+            "id": 7
+        });
+
+        let (status, body) = send(&app, "POST", "/rpc", Some(request)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["jsonrpc"], "2.0");
+        assert_eq!(body["id"], 7);
+        assert!(body.get("error").is_none());
+        assert_eq!(body["result"]["steps_count"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_json_rpc_errors() {
+        let app = create_router_with_state(ReflectionApiState::new());
+
+        let unknown = json!({ "jsonrpc": "2.0", "method": "meditate", "id": "a" });
+        let (status, body) = send(&app, "POST", "/rpc", Some(unknown)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["error"]["code"], -32601);
+        assert_eq!(body["id"], "a");
+
+        let missing_query = json!({ "jsonrpc": "2.0", "method": "reflect", "params": {}, "id": 2 });
+        let (_, body) = send(&app, "POST", "/rpc", Some(missing_query)).await;
+        assert_eq!(body["error"]["code"], -32602);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_rejected() {
+        let app = create_router();
+        let query = "a".repeat(DEFAULT_MAX_BODY_BYTES + 1);
+
+        let (status, _) = send(&app, "POST", "/reflect", Some(json!({ "query": query }))).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+
+        let (status, _) = send(&app, "POST", "/reflect", Some(json!({ "query": "Spike" }))).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_exposition() {
+        let state = ReflectionApiState::new();
+        state.engine.reflect_on_query("How does spike bind ACE2?").unwrap();
+        state.engine.reflect_on_query("Does Omicron escape vaccines?").unwrap();
+        state.engine.record_error("timeout".to_string()).unwrap();
+        let app = create_router_with_state(state);
+
+        let request = Request::builder().uri("/metrics").body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+        let value = |name: &str| -> f64 {
+            body.lines()
+                .find_map(|line| line.strip_prefix(&format!("{} ", name)))
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_else(|| panic!("missing metric {}", name))
+        };
+        assert_eq!(value("reflection_total_steps"), 6.0);
+        assert!((0.0..=1.0).contains(&value("reflection_average_confidence")));
+        assert_eq!(value("reflection_total_errors"), 1.0);
+        assert!(value("reflection_suggestions_total") >= 0.0);
+        assert!(body.contains("# TYPE reflection_total_steps counter"));
+    }
+
+    #[tokio::test]
+    async fn test_errors_ordered_by_frequency() {
+        let state = ReflectionApiState::new();
+        state.engine.record_error("timeout".to_string()).unwrap();
+        for _ in 0..3 {
+            state.engine.record_error("low_confidence".to_string()).unwrap();
+        }
+        let app = create_router_with_state(state);
+
+        let (status, body) = send(&app, "GET", "/errors", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["total_errors"], 4);
+
+        let errors = body["errors"].as_array().unwrap();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0]["error_type"], "low_confidence");
+        assert_eq!(errors[0]["count"], 3);
+        assert_eq!(errors[1]["error_type"], "timeout");
+        assert_eq!(errors[1]["count"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_quality_trend_improves() {
+        let mut state = ReflectionApiState::new().with_quality_trend_capacity(4);
+        state.engine = ReflectionEngine::deterministic(3);
+        // An early error drags quality down; later clean reflections dilute it
+        state.engine.record_error("timeout".to_string()).unwrap();
+        let app = create_router_with_state(state);
+
+        let (_, trend) = send(&app, "GET", "/quality-trend", None).await;
+        assert_eq!(trend, json!([]));
+
+        for _ in 0..5 {
+            let (status, _) = send(&app, "POST", "/reflect", Some(json!({ "query": "Spike binding?" }))).await;
+            assert_eq!(status, StatusCode::OK);
+        }
+
+        let (status, trend) = send(&app, "GET", "/quality-trend", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let quality: Vec<f64> = trend
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r["overall_quality"].as_f64().unwrap())
+            .collect();
+        assert_eq!(quality.len(), 4);
+        assert!(quality.windows(2).all(|w| w[1] >= w[0] - 1e-6), "{:?}", quality);
+    }
+
+    #[tokio::test]
+    async fn test_step_type_distribution() {
+        let state = ReflectionApiState::new();
+        for query in ["How does spike bind ACE2?", "Does Omicron escape antibodies?", "Test query"] {
+            state.engine.reflect_on_query(query).unwrap();
+        }
+        let app = create_router_with_state(state);
+
+        let (status, body) = send(&app, "GET", "/step-types", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["Query"], 3);
+        assert_eq!(body["Reasoning"], 3);
+        assert_eq!(body["Validation"], 3);
+        assert!(body.get("Retrieval").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_poisoned_model_is_server_error() {
+        let state = ReflectionApiState::new();
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            state.engine.update_model(|_| panic!("poison the model lock"))
+        }));
+        let app = create_router_with_state(state);
+
+        for uri in ["/quality", "/errors", "/step-types", "/suggestions"] {
+            let (status, _) = send(&app, "GET", uri, None).await;
+            assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR, "{}", uri);
+        }
+        let (status, _) = send(&app, "POST", "/reflect", Some(json!({ "query": "Spike" }))).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+
+        // Insights are served from counters and stay available
+        let (status, _) = send(&app, "GET", "/insights", None).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
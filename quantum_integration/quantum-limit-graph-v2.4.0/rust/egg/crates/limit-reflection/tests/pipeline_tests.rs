@@ -0,0 +1,134 @@
+// tests/pipeline_tests.rs
+#[cfg(test)]
+mod tests {
+    use limit_benchmark::{GraphMetrics, Intent, IntentType, MultiIntentHarness, MultiIntentQuery, MultiIntentResult};
+    use limit_bio_sars::{BioGraph, HostReceptorNode, ProteinNode, TherapyNode, VariantNode, VirusNode};
+    use limit_hub::governance::{GovernanceRules, Submission};
+    use limit_quantum::{RDCurve, RDPoint};
+    use limit_reflection::{ReflectionEngine, ReflectionGovernance};
+    use std::collections::HashMap;
+
+    /// Same shape as the graph built in `sarscov2_complete_demo.rs`
+    fn build_graph() -> BioGraph {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph.add_variant(VariantNode::new(
+            "Delta".to_string(),
+            vec!["L452R".to_string(), "T478K".to_string()],
+        ));
+        graph.add_variant(VariantNode::new(
+            "Omicron".to_string(),
+            vec!["N501Y".to_string(), "E484A".to_string()],
+        ));
+        graph.add_therapy(TherapyNode::new(
+            "mRNA Vaccine".to_string(),
+            "Induces neutralizing antibodies".to_string(),
+        ));
+        graph
+            .link_with_confidence(
+                spike.id,
+                ace2.id,
+                "binds_to",
+                Some("High affinity binding".to_string()),
+                0.95,
+                vec!["PubMed:12345".to_string(), "Nature:2020".to_string()],
+            )
+            .unwrap();
+        graph
+    }
+
+    fn intent(intent_type: IntentType, query: &str) -> Intent {
+        Intent {
+            intent_type,
+            query: query.to_string(),
+            priority: 1.0,
+            domain: None,
+        }
+    }
+
+    #[test]
+    fn test_full_pipeline() {
+        // Stage 1: graph
+        let graph = build_graph();
+        assert_eq!(graph.node_count(), 6);
+        assert_eq!(graph.edge_count(), 1);
+        assert!(graph.validate().iter().all(|p| matches!(p, limit_bio_sars::GraphProblem::Orphan { .. })));
+
+        // Stage 2: multi-intent benchmark answered from the graph; an intent is
+        // covered when it names a graph node
+        let mut harness = MultiIntentHarness::new();
+        harness.add_query(MultiIntentQuery {
+            id: "q1".to_string(),
+            intents: vec![
+                intent(IntentType::Factual, "What is the Spike Protein?"),
+                intent(IntentType::Causal, "Why do some lineages spread faster?"),
+                intent(IntentType::Comparative, "Compare Delta and Omicron"),
+            ],
+            context: HashMap::new(),
+        });
+        let summary = harness.run_benchmark(|query| {
+            let covered = query
+                .intents
+                .iter()
+                .filter(|i| graph.labelled_nodes().iter().any(|(_, name, _)| i.query.contains(name)))
+                .count();
+            MultiIntentResult {
+                query_id: query.id.clone(),
+                success: covered > 0,
+                latency_ms: 150.0,
+                intent_coverage: covered as f32 / query.intents.len() as f32,
+                provenance: vec!["PubMed:12345".to_string()],
+                intent_latencies_ms: vec![],
+                backend: "simulator".to_string(),
+            }
+        });
+        assert_eq!(summary.total_queries, 1);
+        assert_eq!(summary.successful_queries, 1);
+        assert!((summary.avg_intent_coverage - 2.0 / 3.0).abs() < 1e-6);
+
+        let metrics = GraphMetrics::compute(graph.node_count(), graph.edge_count())
+            .with_coverage(summary.avg_intent_coverage);
+        assert!(metrics.density.is_finite());
+        assert!(metrics.coverage_score.is_finite());
+
+        // Stage 3: RD optimization
+        let mut curve = RDCurve::new();
+        curve.add_point(RDPoint::new(0.6, 0.4, 16, "simulator".to_string()));
+        curve.add_point(RDPoint::new(0.8, 0.2, 32, "simulator".to_string()));
+        curve.add_point(RDPoint::new(0.9, 0.15, 64, "qpu".to_string()));
+        curve.compute_optimal();
+        let optimal = curve.get_optimal().expect("optimal point");
+        assert!(optimal.rate.is_finite() && optimal.distortion.is_finite());
+        assert_eq!(optimal.batch_size, 64);
+
+        // Stage 4: governance
+        let submission = Submission {
+            id: "sub-001".to_string(),
+            content: "Spike Protein binds ACE2".to_string(),
+            confidence: 0.85,
+            provenance: vec!["PubMed".to_string(), "bioRxiv".to_string()],
+            quality_score: 0.9,
+            metadata: HashMap::new(),
+            version: 0,
+            created_at: None,
+            publication_date: None,
+        };
+        let validation = GovernanceRules::default_rules().validate_submission(&submission);
+        assert!(validation.valid, "{:?}", validation.errors);
+
+        // Stage 5: reflection grounded in the graph and seeded by the hub
+        let engine = ReflectionEngine::deterministic(3);
+        engine.seed_from_submissions(&[submission]).unwrap();
+        let result = engine
+            .reflect_with_graph("How does Spike Protein bind ACE2?", &graph)
+            .unwrap();
+        assert!((0.0..=1.0).contains(&result.final_confidence));
+        assert!(result.steps.iter().all(|s| (0.0..=1.0).contains(&s.confidence)));
+
+        let quality = ReflectionGovernance::default_rules().check_quality(&engine.read_model().unwrap());
+        assert!(quality.overall_quality.is_finite());
+    }
+}
@@ -1,7 +1,22 @@
 // tests/reflection_tests.rs
 #[cfg(test)]
 mod tests {
-    use limit_reflection::{ReflectionEngine, ReflectionGovernance, ReflectionRules};
+    use limit_bio_sars::{BioGraph, HostReceptorNode, ProteinNode, VirusNode};
+    use limit_hub::governance::Submission;
+    use limit_reflection::{
+        BudgetExhausted, ComplexityPenalty, PenaltyCurve, QuantumReflector, ReflectionEngine, ReflectionGovernance, ReflectionObserver,
+        ReflectionError, ReflectionRules, ReasoningStep, ReflectionModel, SamplingStrategy, SharedReflectionEngine, StepType, SuggestionType,
+    };
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Panic inside a model update, poisoning the model's lock
+    fn poison_model(engine: &ReflectionEngine) {
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            engine.update_model(|_| panic!("poison the model lock"))
+        }));
+    }
 
     #[test]
     fn test_simple_reflection() {
@@ -35,8 +50,8 @@ mod tests {
     #[test]
     fn test_error_recording() {
         let engine = ReflectionEngine::new(3);
-        engine.record_error("TestError".to_string());
-        engine.record_error("TestError".to_string());
+        engine.record_error("TestError".to_string()).unwrap();
+        engine.record_error("TestError".to_string()).unwrap();
 
         let insights = engine.get_insights();
         assert!(insights.total_errors >= 2);
@@ -50,7 +65,7 @@ mod tests {
         // Perform some reflections
         let _ = engine.reflect_on_query("Test");
 
-        let model = engine.model.read().unwrap();
+        let model = engine.read_model().unwrap();
         let validation = governance.validate_reflection(&*model);
 
         assert!(validation.valid || !validation.errors.is_empty());
@@ -63,7 +78,7 @@ mod tests {
 
         let _ = engine.reflect_on_query("Quality test");
 
-        let model = engine.model.read().unwrap();
+        let model = engine.read_model().unwrap();
         let quality = governance.check_quality(&*model);
 
         assert!(quality.overall_quality >= 0.0);
@@ -85,10 +100,476 @@ mod tests {
 
         let _ = engine.reflect_on_query("Test");
 
-        let model = engine.model.read().unwrap();
+        let model = engine.read_model().unwrap();
         let validation = governance.validate_reflection(&*model);
 
         // With strict rules, validation might fail
         assert!(validation.valid || !validation.warnings.is_empty());
     }
+
+    #[test]
+    fn test_unicode_complexity_uses_char_count() {
+        let reflector = QuantumReflector::new();
+        let greek = "Does variant δ escape ω-class antibodies?";
+        let ascii = "Does variant d escape w-class antibodies?";
+        assert_ne!(greek.len(), ascii.len());
+
+        assert_eq!(
+            reflector.compute_probabilities(greek),
+            reflector.compute_probabilities(ascii)
+        );
+
+        let engine = ReflectionEngine::new(3);
+        let result = engine.reflect_on_query(greek).unwrap();
+        assert!(result.steps[0]
+            .output
+            .contains(&format!("chars: {}", ascii.len())));
+    }
+
+    #[test]
+    fn test_deterministic_reflection() {
+        let query = "How does spike bind ACE2?";
+        let first = ReflectionEngine::deterministic(3).reflect_on_query(query).unwrap();
+        let second = ReflectionEngine::deterministic(3).reflect_on_query(query).unwrap();
+
+        assert_eq!(first.steps.len(), second.steps.len());
+        for (a, b) in first.steps.iter().zip(&second.steps) {
+            assert_eq!(a.output, b.output);
+            assert_eq!(a.confidence.to_bits(), b.confidence.to_bits());
+        }
+        assert_eq!(first.final_confidence.to_bits(), second.final_confidence.to_bits());
+    }
+
+    #[test]
+    fn test_seed_from_submissions() {
+        let engine = ReflectionEngine::new(3);
+        let submissions: Vec<Submission> = [0.9, 0.8, 0.85]
+            .iter()
+            .enumerate()
+            .map(|(i, &confidence)| Submission {
+                id: format!("sub-{}", i),
+                content: "Spike binds ACE2".to_string(),
+                confidence,
+                provenance: vec!["PubMed".to_string()],
+                quality_score: 0.9,
+                metadata: HashMap::new(),
+                version: 1,
+                created_at: None,
+                publication_date: None,
+            })
+            .collect();
+
+        let before = engine.get_insights().total_steps;
+        engine.seed_from_submissions(&submissions).unwrap();
+        let insights = engine.get_insights();
+
+        assert_eq!(insights.total_steps, before + 3);
+        assert!((insights.average_confidence - 0.85).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sampling_strategies_differ() {
+        // A single long token (spike N-terminal residues) gives a peaked input distribution
+        let query = "MFVFLVLLPLVSSQCVNLTTRTQLPPAYTNSFTRGVYYPDKVFRSSVLHSTQDLFLPFFSNVTWFHAIHVSGTNGTKRFDNPVLPFNDGVYFASTEK";
+        let categorical = QuantumReflector::with_seed(42).reflect(query).unwrap();
+        let annealed = QuantumReflector::with_seed(42)
+            .with_strategy(SamplingStrategy::Annealed)
+            .reflect(query)
+            .unwrap();
+
+        assert!((categorical.confidence - annealed.confidence).abs() > 0.1);
+    }
+
+    #[test]
+    fn test_step_budget() {
+        let engine = ReflectionEngine::new(3).with_max_total_steps(6);
+
+        assert!(engine.reflect_on_query("first").is_ok());
+        assert!(engine.reflect_on_query("second").is_ok());
+        assert_eq!(engine.steps_taken(), 6);
+
+        let err = engine.reflect_on_query("third").unwrap_err();
+        assert!(matches!(
+            err,
+            ReflectionError::BudgetExhausted(BudgetExhausted { budget: 6, steps_taken: 6 })
+        ));
+        assert_eq!(engine.get_insights().total_steps, 6);
+    }
+
+    #[test]
+    fn test_step_budget_holds_across_threads() {
+        const THREADS: usize = 16;
+        const BUDGET: usize = 30;
+
+        let engine = SharedReflectionEngine::new(ReflectionEngine::new(3).with_max_total_steps(BUDGET));
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let engine = engine.clone();
+                std::thread::spawn(move || {
+                    (0..5)
+                        .filter(|q| engine.reflect_on_query(&format!("Thread {} query {}", t, q)).is_ok())
+                        .count()
+                })
+            })
+            .collect();
+        let succeeded: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+
+        // Each reflection takes three steps, so exactly ten fit
+        assert_eq!(succeeded, BUDGET / 3);
+        assert_eq!(engine.steps_taken(), BUDGET);
+        assert_eq!(engine.get_insights().total_steps, BUDGET);
+
+        // A reflection that fails after reserving gives its steps back
+        let engine = ReflectionEngine::new(3).with_max_total_steps(3);
+        poison_model(&engine);
+        assert!(matches!(engine.reflect_on_query("Spike"), Err(ReflectionError::LockPoisoned { lock: "model" })));
+        assert_eq!(engine.steps_taken(), 0);
+        // Builders that configure the model report the poisoned lock too
+        assert!(matches!(engine.with_trace_capacity(5), Err(ReflectionError::LockPoisoned { lock: "model" })));
+    }
+
+    #[test]
+    fn test_empty_query_is_rejected() {
+        let engine = ReflectionEngine::new(3);
+        for query in ["", "   \n"] {
+            assert!(matches!(engine.reflect_on_query(query), Err(ReflectionError::EmptyQuery)));
+        }
+        assert!(matches!(engine.deep_reflect(""), Err(ReflectionError::EmptyQuery)));
+        assert_eq!(engine.steps_taken(), 0);
+    }
+
+    #[test]
+    fn test_deep_reflect_continue() {
+        let engine = ReflectionEngine::deterministic(2);
+        let prior = engine.deep_reflect("How does spike bind ACE2?").unwrap();
+        assert_eq!(prior.layers.len(), 2);
+
+        let extended = engine.deep_reflect_continue(&prior, 2).unwrap();
+        assert_eq!(extended.layers.len(), 4);
+        assert_eq!(extended.final_depth, 4);
+        for (before, after) in prior.layers.iter().zip(&extended.layers) {
+            assert_eq!(before.steps[0].id, after.steps[0].id);
+            assert_eq!(before.final_confidence, after.final_confidence);
+        }
+    }
+
+    #[test]
+    fn test_reflect_with_graph_evidence() {
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph
+            .link_with_confidence(
+                spike.id,
+                ace2.id,
+                "binds_to",
+                None,
+                0.95,
+                vec!["PubMed:12345".to_string(), "Nature:2020".to_string()],
+            )
+            .unwrap();
+
+        let engine = ReflectionEngine::new(3);
+        let result = engine.reflect_with_graph("What does Spike do?", &graph).unwrap();
+        let retrieval = result
+            .steps
+            .iter()
+            .find(|s| s.step_type == StepType::Retrieval)
+            .expect("retrieval step");
+
+        let expected = 1.0 - (-graph.node_evidence_score(spike.id)).exp();
+        assert!((retrieval.confidence - expected).abs() < 1e-6);
+        assert!(retrieval.output.contains("Spike Protein"));
+
+        let unrelated = engine.reflect_with_graph("Remdesivir dosing", &graph).unwrap();
+        assert!(unrelated.steps.iter().all(|s| s.step_type != StepType::Retrieval));
+    }
+
+    #[test]
+    fn test_confidences_clamped_to_unit_interval() {
+        // A corrupt edge confidence drives the raw retrieval confidence far below 0
+        let mut graph = BioGraph::new(VirusNode::new("SARS-CoV-2".to_string(), 29.9));
+        let spike = ProteinNode::new("Spike Protein".to_string());
+        let ace2 = HostReceptorNode::new("ACE2".to_string());
+        graph.add_protein(spike.clone());
+        graph.add_receptor(ace2.clone());
+        graph
+            .link_with_confidence(spike.id, ace2.id, "binds_to", None, -5.0, vec!["x".to_string()])
+            .unwrap();
+        let pathological = format!("Spike {}", "\u{0394}".repeat(10_000));
+
+        let engine = ReflectionEngine::new(3);
+        let result = engine.reflect_with_graph(&pathological, &graph).unwrap();
+        let in_unit = |c: f32| (0.0..=1.0).contains(&c);
+        assert!(result.steps.iter().any(|s| s.step_type == StepType::Retrieval));
+        assert!(result.steps.iter().all(|s| in_unit(s.confidence)));
+        assert!(in_unit(result.final_confidence));
+        assert!(in_unit(engine.get_insights().average_confidence));
+
+        let narrow = ReflectionEngine::new(3).with_confidence_bounds(0.2, 0.9).unwrap();
+        let result = narrow.reflect_with_graph(&pathological, &graph).unwrap();
+        assert!(result.steps.iter().all(|s| (0.2..=0.9).contains(&s.confidence)));
+
+        for (floor, ceiling) in [(0.9, 0.2), (-0.1, 0.5), (0.2, 1.5), (f32::NAN, 0.5), (0.2, f32::NAN)] {
+            assert!(matches!(
+                ReflectionEngine::new(3).with_confidence_bounds(floor, ceiling),
+                Err(ReflectionError::InvalidConfidenceBounds { .. })
+            ));
+        }
+    }
+
+    struct CountingObserver(Arc<AtomicUsize>);
+
+    impl ReflectionObserver for CountingObserver {
+        fn on_step(&self, _step: &ReasoningStep) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_observer_notified_per_step() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let engine = ReflectionEngine::new(3).with_observer(CountingObserver(calls.clone()));
+
+        let first = engine.reflect_on_query("How does spike bind ACE2?").unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), first.steps.len());
+
+        let deep = engine.deep_reflect("Does Omicron escape antibodies?").unwrap();
+        let produced: usize = first.steps.len() + deep.layers.iter().map(|l| l.steps.len()).sum::<usize>();
+        assert_eq!(calls.load(Ordering::SeqCst), produced);
+        assert_eq!(calls.load(Ordering::SeqCst), engine.get_insights().total_steps);
+    }
+
+    #[test]
+    fn test_trace_capacity_bounds_memory() {
+        let engine = ReflectionEngine::new(3).with_trace_capacity(5).unwrap();
+
+        let mut previous_total = 0;
+        for i in 0..10 {
+            engine.reflect_on_query(&format!("query {}", i)).unwrap();
+            let insights = engine.get_insights();
+            assert!(insights.total_steps > previous_total);
+            previous_total = insights.total_steps;
+        }
+
+        let model = engine.read_model().unwrap();
+        assert_eq!(model.reasoning_trace.len(), 5);
+        assert_eq!(model.confidence_history.len(), 5);
+        assert_eq!(model.evicted_steps, 25);
+        assert_eq!(engine.get_insights().total_steps, 30);
+        assert!((0.0..=1.0).contains(&engine.get_insights().average_confidence));
+    }
+
+    #[test]
+    fn test_exponential_complexity_penalty_exceeds_linear() {
+        let query = "How do the spike protein mutations found in recent variants change binding to ACE2 \
+                     and antibody escape across different host tissues?";
+        let complexity_confidence = |curve| {
+            let engine = ReflectionEngine::deterministic(3).with_complexity_penalty(ComplexityPenalty::new(curve, 0.3));
+            engine.reflect_on_query(query).unwrap().steps[0].confidence
+        };
+
+        let linear = complexity_confidence(PenaltyCurve::Linear);
+        let exponential = complexity_confidence(PenaltyCurve::Exponential);
+        assert!(exponential < linear, "{} !< {}", exponential, linear);
+        // The default keeps the original linear 0.3 penalty
+        let default = ReflectionEngine::deterministic(3).reflect_on_query(query).unwrap();
+        assert_eq!(default.steps[0].confidence, linear);
+
+        let sigmoid = ComplexityPenalty::new(PenaltyCurve::Sigmoid, 0.3);
+        assert!(sigmoid.penalty(0.0).abs() < 1e-6);
+        assert!((sigmoid.penalty(1.0) - 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_prune_suggestions_below_priority() {
+        let mut model = ReflectionModel::new();
+        for step_type in [StepType::Query, StepType::Reasoning, StepType::Critique] {
+            model.add_step(ReasoningStep::new(step_type, "in".to_string(), "out".to_string(), 0.3));
+        }
+        for _ in 0..4 {
+            model.record_error("timeout".to_string());
+        }
+
+        // Low confidence (0.8), recurring error (0.9) and critique (0.7)
+        model.generate_suggestions();
+        assert_eq!(model.improvement_suggestions.len(), 3);
+
+        model.prune_suggestions(0.85);
+        assert_eq!(model.improvement_suggestions.len(), 1);
+        assert_eq!(model.improvement_suggestions[0].suggestion_type, SuggestionType::FixRecurringError);
+
+        // With a floor, regeneration prunes on its own
+        model.suggestion_floor = Some(0.85);
+        model.generate_suggestions();
+        assert!(model.improvement_suggestions.iter().all(|s| s.priority >= 0.85));
+        assert_eq!(model.improvement_suggestions.len(), 1);
+    }
+
+    #[test]
+    fn test_critique_low_confidence_conclusion() {
+        // A ceiling of 0.4 forces every conclusion below the critique threshold
+        let engine = ReflectionEngine::deterministic(3)
+            .with_confidence_bounds(0.0, 0.4)
+            .unwrap()
+            .with_critique(0.5);
+        let result = engine.reflect_on_query("Might spike mutations evade ACE2 antibodies?").unwrap();
+
+        let critique = result.steps.last().unwrap();
+        assert_eq!(critique.step_type, StepType::Critique);
+        assert!(critique.output.contains("assumptions:"));
+        assert!(critique.output.contains("the query is unambiguous"));
+        assert!(result.final_confidence < 0.5);
+
+        let suggestions = engine.get_suggestions().unwrap();
+        assert!(suggestions
+            .iter()
+            .any(|s| s.suggestion_type == SuggestionType::EnhanceValidation));
+
+        // Confident conclusions are not critiqued
+        let confident = ReflectionEngine::deterministic(3).with_critique(0.1);
+        let result = confident.reflect_on_query("Test query").unwrap();
+        assert!(result.steps.iter().all(|s| s.step_type != StepType::Critique));
+    }
+
+    #[test]
+    fn test_governance_check_penalizes_shallow_trace() {
+        let query = "How does spike bind ACE2?";
+        // The pipeline takes three steps before validation, short of ten
+        let strict = ReflectionRules {
+            min_average_confidence: 0.0,
+            min_reasoning_steps: 10,
+            ..ReflectionRules::default()
+        };
+        let engine = ReflectionEngine::deterministic(3).with_governance_check(strict);
+        let result = engine.reflect_on_query(query).unwrap();
+
+        let validation = result.steps.last().unwrap();
+        assert_eq!(validation.step_type, StepType::Validation);
+        assert!(validation.output.contains("Reasoning steps 3 below minimum 10"));
+        assert_eq!(validation.metadata["governance_warnings"], "1");
+        let conclusion = result.steps[result.steps.len() - 2].confidence;
+        assert!((validation.confidence - (conclusion - 0.1)).abs() < 1e-6);
+        assert_eq!(result.final_confidence, validation.confidence);
+
+        // A trace that satisfies the rules keeps its confidence
+        let lenient = ReflectionRules {
+            min_average_confidence: 0.0,
+            min_reasoning_steps: 1,
+            ..ReflectionRules::default()
+        };
+        let engine = ReflectionEngine::deterministic(3).with_governance_check(lenient);
+        let result = engine.reflect_on_query(query).unwrap();
+        let validation = result.steps.last().unwrap();
+        assert_eq!(validation.confidence, result.steps[result.steps.len() - 2].confidence);
+    }
+
+    #[test]
+    fn test_concurrent_reflection_on_shared_engine() {
+        const THREADS: usize = 16;
+        const QUERIES_PER_THREAD: usize = 10;
+
+        let engine = SharedReflectionEngine::new(ReflectionEngine::new(3));
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let engine = engine.clone();
+                std::thread::spawn(move || {
+                    (0..QUERIES_PER_THREAD)
+                        .map(|q| {
+                            let query = format!("Thread {} query {} on spike binding", t, q);
+                            let steps = engine.reflect_on_query(&query).unwrap().steps.len();
+                            engine.record_error("timeout".to_string()).unwrap();
+                            // Readers interleave with writers
+                            assert!(engine.get_insights().total_steps >= steps);
+                            steps
+                        })
+                        .sum::<usize>()
+                })
+            })
+            .collect();
+        let total: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+
+        assert_eq!(total, THREADS * QUERIES_PER_THREAD * 3);
+        assert_eq!(engine.steps_taken(), total);
+
+        // Insights come from atomics, so readers don't wait on the model lock
+        let insights = engine
+            .update_model(|_| {
+                let reader = engine.clone();
+                std::thread::spawn(move || reader.get_insights()).join().unwrap()
+            })
+            .unwrap();
+        assert_eq!(insights.total_steps, total);
+        assert_eq!(insights.total_errors, THREADS * QUERIES_PER_THREAD);
+        assert_eq!(insights.unique_error_types, 1);
+        assert!((0.0..=1.0).contains(&insights.average_confidence));
+    }
+
+    #[test]
+    fn test_deterministic_result_cache() {
+        let engine = ReflectionEngine::deterministic(3).with_cache(8);
+
+        let first = engine.reflect_on_query("How does spike bind ACE2?").unwrap();
+        let second = engine.reflect_on_query("How does spike bind ACE2?").unwrap();
+
+        let insights = engine.get_insights();
+        assert_eq!((insights.cache_hits, insights.cache_misses), (1, 1));
+        assert_eq!(insights.total_steps, first.steps.len());
+        assert_eq!(first.final_confidence, second.final_confidence);
+        let ids = |r: &limit_reflection::ReflectionResult| r.steps.iter().map(|s| s.id).collect::<Vec<_>>();
+        assert_eq!(ids(&first), ids(&second));
+
+        // Stochastic engines never consult the cache
+        let stochastic = ReflectionEngine::new(3).with_cache(8);
+        stochastic.reflect_on_query("How does spike bind ACE2?").unwrap();
+        stochastic.reflect_on_query("How does spike bind ACE2?").unwrap();
+        assert_eq!(stochastic.get_insights().cache_hits, 0);
+    }
+
+    #[test]
+    fn test_replay_stored_trace() {
+        let mut stored = ReflectionModel::new();
+        for (i, confidence) in [0.2, 0.4, 0.6, 0.8, 1.0].into_iter().enumerate() {
+            stored.add_step(ReasoningStep::new(
+                StepType::Reasoning,
+                format!("step {}", i),
+                String::new(),
+                confidence,
+            ));
+        }
+        let imported: ReflectionModel =
+            serde_json::from_str(&serde_json::to_string(&stored).unwrap()).unwrap();
+
+        let engine = ReflectionEngine::new(3);
+        let snapshots = engine.replay(&imported);
+
+        assert_eq!(snapshots.len(), 5);
+        for (i, (snapshot, expected)) in snapshots.iter().zip([0.2, 0.3, 0.4, 0.5, 0.6]).enumerate() {
+            assert_eq!(snapshot.total_steps, i + 1);
+            assert!((snapshot.average_confidence - expected).abs() < 1e-6);
+        }
+        // Replay never touches the engine's own model
+        assert_eq!(engine.get_insights().total_steps, 0);
+    }
+
+    #[test]
+    fn test_explain_reflection() {
+        let engine = ReflectionEngine::new(3);
+        let result = engine.reflect_on_query("How does spike bind ACE2?").unwrap();
+
+        let explanation = engine.explain(&result);
+        let lowest = result
+            .steps
+            .iter()
+            .min_by(|a, b| a.confidence.partial_cmp(&b.confidence).unwrap())
+            .unwrap();
+
+        assert!(explanation.contains(&format!("{} reasoning steps", result.steps.len())));
+        assert!(explanation.contains(&format!(
+            "Lowest confidence came from the {:?} step at {:.2}",
+            lowest.step_type, lowest.confidence
+        )));
+    }
 }
@@ -46,7 +46,7 @@ fn main() {
 
     // Example 4: Get improvement suggestions
     println!("Example 4: Improvement Suggestions");
-    let suggestions = engine.get_suggestions();
+    let suggestions = engine.get_suggestions().unwrap();
     println!("  Total Suggestions: {}", suggestions.len());
     for (i, suggestion) in suggestions.iter().enumerate() {
         println!("  {}. {:?} (priority: {:.2})", i + 1, suggestion.suggestion_type, suggestion.priority);
@@ -56,7 +56,7 @@ fn main() {
 
     // Example 5: Quality check
     println!("Example 5: Quality Governance");
-    let model = engine.model.read().unwrap();
+    let model = engine.read_model().unwrap();
     let quality = governance.check_quality(&*model);
     println!("  Overall Quality: {:.2}", quality.overall_quality);
     println!("  Confidence Score: {:.2}", quality.confidence_score);
@@ -81,7 +81,8 @@ fn build_sarscov2_graph() -> BioGraph {
         Some("High affinity binding".to_string()),
         0.95,
         vec!["PubMed:12345".to_string(), "Nature:2020".to_string()]
-    );
+    )
+    .expect("relation accepted");
 
     graph
 }
@@ -119,6 +120,8 @@ fn run_multi_intent_benchmark() -> limit_benchmark::BenchmarkSummary {
             latency_ms: 150.0,
             intent_coverage: 0.85,
             provenance: vec!["PubMed".to_string()],
+            intent_latencies_ms: vec![60.0, 90.0],
+            backend: "simulator".to_string(),
         }
     })
 }
@@ -145,6 +148,9 @@ fn validate_with_governance() -> limit_hub::governance::ValidationResult {
         provenance: vec!["PubMed".to_string(), "bioRxiv".to_string()],
         quality_score: 0.9,
         metadata: HashMap::new(),
+        version: 0,
+        created_at: None,
+        publication_date: None,
     };
 
     rules.validate_submission(&submission)